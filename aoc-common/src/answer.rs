@@ -0,0 +1,164 @@
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// A puzzle answer, keeping its type so it can be compared numerically and serialised as such.
+///
+/// Numbers compare by value whatever their variant, anything else by its [`Display`]ed form, so
+/// an answer equals the string recorded for it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+    /// Multi-line answer such as a rendered grid, one string per row.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// The value of a numeric answer, wide enough to compare unsigned and signed ones.
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Unsigned(n) => Some(i128::from(*n)),
+            Answer::Signed(n) => Some(i128::from(*n)),
+            Answer::Text(_) | Answer::Grid(_) => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+/// Infers the variant: integers become numbers, text with line breaks a grid, anything else text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim_end_matches(['\r', '\n']);
+        Ok(if let Ok(n) = s.parse() {
+            Answer::Unsigned(n)
+        } else if let Ok(n) = s.parse() {
+            Answer::Signed(n)
+        } else if s.contains('\n') {
+            Answer::Grid(s.lines().map(str::to_owned).collect())
+        } else {
+            Answer::Text(s.to_owned())
+        })
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.as_integer(), other.as_integer()) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.to_string() == other.to_string(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        let Ok(other) = other.parse::<Answer>();
+        *self == other
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Unsigned(n as u64)
+            }
+        })*
+    };
+}
+
+macro_rules! from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Signed(n as i64)
+            }
+        })*
+    };
+}
+
+from_unsigned!(u8, u16, u32, u64, usize);
+from_signed!(i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(rows: Vec<String>) -> Self {
+        Answer::Grid(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_test() {
+        assert!(matches!("42".parse(), Ok(Answer::Unsigned(42))));
+        assert!(matches!("-7".parse(), Ok(Answer::Signed(-7))));
+        assert!(matches!("abc".parse(), Ok(Answer::Text(t)) if t == "abc"));
+        assert!(matches!("#.\n.#\n".parse(), Ok(Answer::Grid(rows)) if rows == ["#.", ".#"]));
+    }
+
+    #[test]
+    fn display_round_trips_test() {
+        for answer in [Answer::Unsigned(18446744073709551615), Answer::Signed(-3), Answer::from("a,b"), Answer::from(vec!["##".to_owned(), "..".to_owned()])] {
+            assert_eq!(answer.to_string().parse::<Answer>().unwrap(), answer);
+        }
+    }
+
+    #[test]
+    fn eq_test() {
+        assert_eq!(Answer::Unsigned(5), Answer::Signed(5));
+        assert_eq!(Answer::Unsigned(5), Answer::Text("5".to_owned()));
+        assert_ne!(Answer::Unsigned(5), Answer::Text("05".to_owned()));
+        assert_ne!(Answer::Signed(-1), Answer::Unsigned(u64::MAX));
+        assert_eq!(Answer::from(2904518_u64), "2904518");
+        assert_ne!(Answer::from(2904518_u64), "2904519");
+        assert_eq!(Answer::from(vec!["ab".to_owned(), "cd".to_owned()]), "ab\ncd");
+    }
+
+    #[test]
+    fn serialize_keeps_type_test() {
+        // when
+        let serialized = toml::to_string(&std::collections::BTreeMap::from([("answer", Answer::Signed(-3))])).unwrap();
+
+        // then
+        assert_eq!(serialized, "[answer]\ntype = \"signed\"\nvalue = -3\n");
+    }
+}
@@ -0,0 +1,146 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, CHACHA20_POLY1305, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+
+/// Hex encoded key, taking precedence over any key file.
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+/// Path of the file holding the hex encoded key.
+pub const KEY_FILE_VAR: &str = "AOC_INPUT_KEY_FILE";
+/// Key file looked up at the workspace root when neither variable is set.
+pub const DEFAULT_KEY_FILE: &str = "input.key";
+
+/// Suffix of an encrypted input next to where its plaintext would be, e.g. `input.txt.enc`.
+pub const ENCRYPTED_SUFFIX: &str = ".enc";
+
+const MAGIC: &[u8] = b"aoc-enc1";
+
+/// Path of the encrypted form of the input at `path`.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut encrypted = path.as_os_str().to_owned();
+    encrypted.push(ENCRYPTED_SUFFIX);
+    PathBuf::from(encrypted)
+}
+
+/// 256-bit ChaCha20-Poly1305 key used to encrypt puzzle inputs.
+#[derive(Clone)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn generate() -> anyhow::Result<Self> {
+        let mut bytes = [0; 32];
+        SystemRandom::new().fill(&mut bytes).map_err(|_| anyhow::anyhow!("no randomness available"))?;
+        Ok(Self(bytes))
+    }
+
+    /// Reads the key from `$AOC_INPUT_KEY`, the file named by `$AOC_INPUT_KEY_FILE`, or
+    /// `input.key` in `workspace_root`, in that order.
+    pub fn locate(workspace_root: Option<&Path>) -> anyhow::Result<Self> {
+        if let Ok(hex) = env::var(KEY_VAR) {
+            return hex.parse().with_context(|| format!("invalid key in {KEY_VAR}"));
+        }
+        let path = env::var_os(KEY_FILE_VAR).map(PathBuf::from)
+            .or_else(|| workspace_root.map(|root| root.join(DEFAULT_KEY_FILE)))
+            .with_context(|| format!("no input key, set {KEY_VAR} or {KEY_FILE_VAR}"))?;
+        let hex = fs::read_to_string(&path)
+            .with_context(|| format!("no input key, set {KEY_VAR} or create {}", path.display()))?;
+        hex.parse().with_context(|| format!("invalid key in {}", path.display()))
+    }
+
+    fn aead(&self) -> LessSafeKey {
+        LessSafeKey::new(UnboundKey::new(&CHACHA20_POLY1305, &self.0).expect("key has the cipher's length"))
+    }
+
+    pub fn encrypt(&self, plaintext: &str) -> anyhow::Result<Vec<u8>> {
+        let mut nonce = [0; NONCE_LEN];
+        SystemRandom::new().fill(&mut nonce).map_err(|_| anyhow::anyhow!("no randomness available"))?;
+        let mut sealed = plaintext.as_bytes().to_vec();
+        self.aead().seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::from(MAGIC), &mut sealed)
+            .map_err(|_| anyhow::anyhow!("failed to encrypt"))?;
+        Ok([MAGIC, &nonce, &sealed].concat())
+    }
+
+    pub fn decrypt(&self, encrypted: &[u8]) -> anyhow::Result<String> {
+        let sealed = encrypted.strip_prefix(MAGIC).context("not an encrypted input")?;
+        anyhow::ensure!(sealed.len() >= NONCE_LEN, "encrypted input is truncated");
+        let (nonce, sealed) = sealed.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce).expect("nonce has the cipher's length");
+        let mut sealed = sealed.to_vec();
+        let plaintext = self.aead().open_in_place(nonce, Aad::from(MAGIC), &mut sealed)
+            .map_err(|_| anyhow::anyhow!("failed to decrypt, the key is wrong or the input was modified"))?;
+        Ok(String::from_utf8(plaintext.to_vec())?)
+    }
+}
+
+impl std::fmt::Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl std::str::FromStr for Key {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        anyhow::ensure!(s.len() == 64 && s.is_ascii(), "expected 64 hex digits");
+        let mut bytes = [0; 32];
+        for (byte, hex) in bytes.iter_mut().zip(s.as_bytes().chunks(2)) {
+            *byte = u8::from_str_radix(std::str::from_utf8(hex)?, 16)?;
+        }
+        Ok(Self(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_test() {
+        // given
+        let key = Key::generate().unwrap();
+
+        // when
+        let encrypted = key.encrypt("3   4\n4   3\n").unwrap();
+
+        // then
+        assert!(!encrypted.windows(5).any(|w| w == b"3   4"));
+        assert_eq!(key.decrypt(&encrypted).unwrap(), "3   4\n4   3\n");
+    }
+
+    #[test]
+    fn wrong_key_or_tampering_fails_test() {
+        // given
+        let key = Key::generate().unwrap();
+        let mut encrypted = key.encrypt("secret").unwrap();
+
+        // then
+        assert!(Key::generate().unwrap().decrypt(&encrypted).is_err());
+        *encrypted.last_mut().unwrap() ^= 1;
+        assert!(key.decrypt(&encrypted).is_err());
+        assert!(key.decrypt(b"plain text").is_err());
+    }
+
+    #[test]
+    fn key_hex_round_trip_test() {
+        // given
+        let key = Key::generate().unwrap();
+
+        // when
+        let parsed: Key = format!("{key}\n").parse().unwrap();
+
+        // then
+        assert_eq!(parsed.0, key.0);
+        assert!("abc".parse::<Key>().is_err());
+        assert!("zz".repeat(32).parse::<Key>().is_err());
+    }
+
+    #[test]
+    fn encrypted_path_test() {
+        assert_eq!(encrypted_path(Path::new("day1/resources/input.txt")), PathBuf::from("day1/resources/input.txt.enc"));
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde::Deserialize;
+
+use crate::{Part, Solution};
+
+/// Directory of a day's crate holding `<name>.txt` example inputs and the [`MANIFEST`].
+pub const EXAMPLES_DIR: &str = "resources/examples";
+
+/// Expected answers keyed as `[name] partP = "answer"`, one table per example input.
+pub const MANIFEST: &str = "examples.toml";
+
+/// One puzzle example and the answers it is known to produce.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Example {
+    pub name: String,
+    pub path: PathBuf,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn input(&self) -> anyhow::Result<String> {
+        fs::read_to_string(&self.path)
+            .with_context(|| format!("failed to read example {}", self.path.display()))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Answers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+/// Reads the examples listed in `<manifest_dir>/resources/examples/examples.toml`, sorted by name.
+///
+/// A day without a manifest simply has no examples.
+pub fn load(manifest_dir: &str) -> anyhow::Result<Vec<Example>> {
+    let dir = Path::new(manifest_dir).join(EXAMPLES_DIR);
+    let manifest = dir.join(MANIFEST);
+    if !manifest.exists() {
+        return Ok(vec![]);
+    }
+    let content = fs::read_to_string(&manifest)
+        .with_context(|| format!("failed to read {}", manifest.display()))?;
+    let answers: BTreeMap<String, Answers> = toml::from_str(&content)
+        .with_context(|| format!("invalid example manifest {}", manifest.display()))?;
+    answers.into_iter()
+        .map(|(name, Answers { part1, part2 })| {
+            let path = dir.join(format!("{name}.txt"));
+            anyhow::ensure!(path.is_file(), "example `{name}` listed in {} has no input file {}", manifest.display(), path.display());
+            Ok(Example { name, path, part1, part2 })
+        })
+        .collect()
+}
+
+/// Build script entry point: writes one `#[test]` per example and answered part to
+/// `$OUT_DIR/examples.rs`, which [`example_tests!`](crate::example_tests) includes.
+pub fn generate_tests() -> anyhow::Result<()> {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR")?;
+    let out_dir = env::var("OUT_DIR")?;
+    println!("cargo:rerun-if-changed={EXAMPLES_DIR}");
+    fs::write(Path::new(&out_dir).join("examples.rs"), tests_source(&load(&manifest_dir)?))?;
+    Ok(())
+}
+
+fn tests_source(examples: &[Example]) -> String {
+    let mut source = String::new();
+    for example in examples {
+        let name: String = example.name.chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+            .collect();
+        for part in Part::ALL {
+            let Some(answer) = example.answer(part) else { continue };
+            // writing to a String cannot fail
+            let _ = writeln!(source, "#[test]
+fn {name}_part{part}() {{
+    ::aoc_common::examples::check::<Day>(include_str!({:?}), ::aoc_common::Part::{part:?}, {answer:?});
+}}", example.path.display().to_string());
+        }
+    }
+    source
+}
+
+/// Asserts that solving `part` of `input` gives an answer equal to `expected`; called by the
+/// generated example tests.
+pub fn check<S: Solution>(input: &str, part: Part, expected: &str) {
+    let input = S::parse(input).unwrap_or_else(|err| panic!("failed to parse example: {err:#}"));
+    let answer = S::solve(&input, part).unwrap_or_else(|err| panic!("failed to solve part {part}: {err:#}"));
+    assert_eq!(answer, expected);
+}
+
+/// Includes the tests written by [`generate_tests`] from the day's build script, run against `$day`.
+#[macro_export]
+macro_rules! example_tests {
+    ($day:ty) => {
+        #[cfg(test)]
+        mod examples {
+            type Day = $day;
+            include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tests_source_test() {
+        // given
+        let examples = vec![Example {
+            name: "larger-example".to_owned(),
+            path: PathBuf::from("/day/resources/examples/larger-example.txt"),
+            part1: None,
+            part2: Some("9".to_owned()),
+        }];
+
+        // when
+        let source = tests_source(&examples);
+
+        // then
+        assert_eq!(source, r#"#[test]
+fn larger_example_part2() {
+    ::aoc_common::examples::check::<Day>(include_str!("/day/resources/examples/larger-example.txt"), ::aoc_common::Part::Two, "9");
+}
+"#);
+    }
+
+    #[test]
+    fn load_without_manifest_test() {
+        // when
+        let examples = load(env!("CARGO_MANIFEST_DIR")).unwrap();
+
+        // then
+        assert!(examples.is_empty());
+    }
+}
@@ -0,0 +1,320 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Signed 2D point, doubling as a vector between points.
+///
+/// `x` grows to the right and `y` grows downwards, matching how puzzle grids are read.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ZERO: Point = Point::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// Point of the grid cell at column `x` and row `y`.
+    pub fn from_index(x: usize, y: usize) -> Self {
+        Self::new(x as isize, y as isize)
+    }
+
+    /// Column and row of this point, or `None` when either coordinate is negative.
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+
+    /// Whether the point lies in a `width` x `height` area anchored at the origin.
+    pub fn is_within(self, width: usize, height: usize) -> bool {
+        self.to_index().is_some_and(|(x, y)| x < width && y < height)
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Add<Direction4> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Direction4) -> Self::Output {
+        self + rhs.vector()
+    }
+}
+
+impl Add<Direction8> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Direction8) -> Self::Output {
+        self + rhs.vector()
+    }
+}
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    const CLOCKWISE: [Direction4; 4] = [Direction4::Up, Direction4::Right, Direction4::Down, Direction4::Left];
+
+    /// All directions, clockwise starting from `Up`.
+    pub fn all() -> impl Iterator<Item=Direction4> {
+        Self::CLOCKWISE.into_iter()
+    }
+
+    pub fn vector(self) -> Point {
+        match self {
+            Direction4::Up => Point::new(0, -1),
+            Direction4::Right => Point::new(1, 0),
+            Direction4::Down => Point::new(0, 1),
+            Direction4::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction4 {
+        self.rotate(1)
+    }
+
+    pub fn turn_left(self) -> Direction4 {
+        self.rotate(3)
+    }
+
+    pub fn reverse(self) -> Direction4 {
+        self.rotate(2)
+    }
+
+    fn rotate(self, quarter_turns: usize) -> Direction4 {
+        Self::CLOCKWISE[(self as usize + quarter_turns) % 4]
+    }
+}
+
+impl Mul<isize> for Direction4 {
+    type Output = Point;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        self.vector() * rhs
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(value: Direction4) -> Self {
+        match value {
+            Direction4::Up => Direction8::N,
+            Direction4::Right => Direction8::E,
+            Direction4::Down => Direction8::S,
+            Direction4::Left => Direction8::W,
+        }
+    }
+}
+
+/// One of the four orthogonal and four diagonal directions, named after compass points with `N` up.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    const CLOCKWISE: [Direction8; 8] = [
+        Direction8::N, Direction8::NE, Direction8::E, Direction8::SE,
+        Direction8::S, Direction8::SW, Direction8::W, Direction8::NW,
+    ];
+
+    /// All directions, clockwise starting from `N`.
+    pub fn all() -> impl Iterator<Item=Direction8> {
+        Self::CLOCKWISE.into_iter()
+    }
+
+    pub fn vector(self) -> Point {
+        match self {
+            Direction8::N => Point::new(0, -1),
+            Direction8::NE => Point::new(1, -1),
+            Direction8::E => Point::new(1, 0),
+            Direction8::SE => Point::new(1, 1),
+            Direction8::S => Point::new(0, 1),
+            Direction8::SW => Point::new(-1, 1),
+            Direction8::W => Point::new(-1, 0),
+            Direction8::NW => Point::new(-1, -1),
+        }
+    }
+
+    /// Next direction clockwise, an eighth of a turn away.
+    pub fn turn_right(self) -> Direction8 {
+        self.rotate(1)
+    }
+
+    /// Next direction counter-clockwise, an eighth of a turn away.
+    pub fn turn_left(self) -> Direction8 {
+        self.rotate(7)
+    }
+
+    pub fn reverse(self) -> Direction8 {
+        self.rotate(4)
+    }
+
+    fn rotate(self, eighth_turns: usize) -> Direction8 {
+        Self::CLOCKWISE[(self as usize + eighth_turns) % 8]
+    }
+}
+
+impl Mul<isize> for Direction8 {
+    type Output = Point;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        self.vector() * rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turn_right_test() {
+        // given
+        let d = Direction4::Right; // >
+
+        // when
+        let d = d.turn_right();
+
+        // then
+        assert_eq!(d, Direction4::Down); // \/
+
+        // when
+        let d = d.turn_right();
+
+        // then
+        assert_eq!(d, Direction4::Left); // <
+
+        // when
+        let d = d.turn_right();
+
+        // then
+        assert_eq!(d, Direction4::Up); // /\
+
+        // when
+        let d = d.turn_right();
+
+        // then
+        assert_eq!(d, Direction4::Right); // >
+    }
+
+    #[test]
+    fn turn_left_and_reverse_test() {
+        for d in Direction4::all() {
+            assert_eq!(d.turn_right().turn_left(), d);
+            assert_eq!(d.reverse().vector(), -d.vector());
+        }
+        for d in Direction8::all() {
+            assert_eq!(d.turn_right().turn_left(), d);
+            assert_eq!(d.reverse().vector(), -d.vector());
+        }
+    }
+
+    #[test]
+    fn direction8_turns_by_eighths_test() {
+        assert_eq!(Direction8::N.turn_right(), Direction8::NE);
+        assert_eq!(Direction8::N.turn_left(), Direction8::NW);
+        assert_eq!(Direction8::from(Direction4::Left), Direction8::W);
+        assert_eq!(Direction8::all().count(), 8);
+    }
+
+    #[test]
+    fn scalar_multiplication_test() {
+        assert_eq!(Direction8::SW * 3, Point::new(-3, 3));
+        assert_eq!(Point::new(2, -1) * -2, Point::new(-4, 2));
+        assert_eq!(Point::new(1, 1) + Direction4::Up * 2, Point::new(1, -1));
+    }
+
+    #[test]
+    fn to_index_test() {
+        assert_eq!(Point::new(3, 4).to_index(), Some((3, 4)));
+        assert_eq!(Point::new(-1, 4).to_index(), None);
+        assert_eq!((Point::ZERO + Direction4::Up).to_index(), None);
+    }
+
+    #[test]
+    fn is_within_test() {
+        assert!(Point::new(8, 9).is_within(10, 10));
+        assert!(!Point::new(8, 10).is_within(10, 10));
+        assert!(!Point::new(-1, 0).is_within(10, 10));
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        // given
+        let a = Point::new(4, 3);
+        let b = Point::new(5, 5);
+
+        // when
+        let antinode = a - b + a;
+
+        // then
+        assert_eq!(antinode, Point::new(3, 1));
+        assert_eq!(a.manhattan_distance(b), 3);
+    }
+}
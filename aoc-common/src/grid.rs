@@ -0,0 +1,312 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::geometry::{Direction4, Direction8, Point};
+
+/// Rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self, GridError> {
+        if cells.len() != width * height {
+            return Err(GridError::CellCount { width, height, cells: cells.len() });
+        }
+        Ok(Self { cells, width, height })
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { cells: vec![value; width * height], width, height }
+    }
+
+    /// Parses one row per line, mapping every character with `cell`.
+    ///
+    /// All rows must have the same width and `cell` must accept every character.
+    pub fn parse_with(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                cells.push(cell(c).ok_or(GridError::InvalidCell { position: Point::from_index(x, y), value: c })?);
+                row_width += 1;
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(GridError::RaggedRow { line: y + 1, expected: width, actual: row_width });
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Self { cells, width, height }),
+            _ => Err(GridError::Empty),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        pos.is_within(self.width, self.height)
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Orthogonal neighbours of `pos` that lie on the grid, clockwise starting from the one above.
+    pub fn neighbours4(&self, pos: Point) -> impl Iterator<Item=Point> + '_ {
+        Direction4::all().map(move |d| pos + d).filter(|&p| self.contains(p))
+    }
+
+    /// Orthogonal and diagonal neighbours of `pos` that lie on the grid, clockwise starting from the one above.
+    pub fn neighbours8(&self, pos: Point) -> impl Iterator<Item=Point> + '_ {
+        Direction8::all().map(move |d| pos + d).filter(|&p| self.contains(p))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item=Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point::from_index(i % width, i / width))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item=(Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The cells row by row, none at all for a grid without columns.
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Position of the first cell, row by row, equal to `value`.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.iter().find_map(|(pos, cell)| (cell == value).then_some(pos))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height }
+    }
+
+    fn index_of(&self, pos: Point) -> Option<usize> {
+        let (x, y) = pos.to_index()?;
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("position {pos} is outside of {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {pos} is outside of {width}x{height} grid"))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, Some)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum GridError {
+    Empty,
+    RaggedRow { line: usize, expected: usize, actual: usize },
+    InvalidCell { position: Point, value: char },
+    CellCount { width: usize, height: usize, cells: usize },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "grid has no cells"),
+            GridError::RaggedRow { line, expected, actual } =>
+                write!(f, "line {line} has {actual} cells, expected {expected} like the first row"),
+            GridError::InvalidCell { position, value } =>
+                write!(f, "unexpected {value:?} at line {}, column {}", position.y + 1, position.x + 1),
+            GridError::CellCount { width, height, cells } =>
+                write!(f, "{width}x{height} grid needs {} cells, got {cells}", width * height),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        // given
+        let input = "ab\ncd\nef\n";
+
+        // when
+        let grid: Grid<char> = input.parse().unwrap();
+
+        // then
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.get(Point::new(1, 0)), Some(&'b'));
+        assert_eq!(grid.get(Point::new(0, 2)), Some(&'e'));
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 3)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+    }
+
+    #[test]
+    fn parse_crlf_test() {
+        // given
+        let input = "ab\r\ncd\r\n";
+
+        // when
+        let grid: Grid<char> = input.parse().unwrap();
+
+        // then
+        assert_eq!(grid.to_string(), "ab\ncd");
+    }
+
+    #[test]
+    fn parse_ragged_rows_test() {
+        // given
+        let input = "abc\nde\nfgh";
+
+        // when
+        let result = input.parse::<Grid<char>>();
+
+        // then
+        assert_eq!(result, Err(GridError::RaggedRow { line: 2, expected: 3, actual: 2 }));
+    }
+
+    #[test]
+    fn parse_empty_test() {
+        assert_eq!("".parse::<Grid<char>>(), Err(GridError::Empty));
+        assert_eq!("\n".parse::<Grid<char>>(), Err(GridError::Empty));
+    }
+
+    #[test]
+    fn parse_with_rejects_unknown_cells_test() {
+        // given
+        let input = "..#\n.x.";
+
+        // when
+        let result = Grid::parse_with(input, |c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        });
+
+        // then
+        assert_eq!(result, Err(GridError::InvalidCell { position: Point::new(1, 1), value: 'x' }));
+    }
+
+    #[test]
+    fn neighbours_test() {
+        // given
+        let grid = Grid::filled(3, 3, 0);
+
+        // when
+        let corner4: Vec<_> = grid.neighbours4(Point::ZERO).collect();
+        let corner8: Vec<_> = grid.neighbours8(Point::ZERO).collect();
+        let center8 = grid.neighbours8(Point::new(1, 1)).count();
+
+        // then
+        assert_eq!(corner4, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(corner8, vec![Point::new(1, 0), Point::new(1, 1), Point::new(0, 1)]);
+        assert_eq!(center8, 8);
+    }
+
+    #[test]
+    fn find_test() {
+        // given
+        let grid: Grid<char> = "..\n.^".parse().unwrap();
+
+        // when
+        let found = grid.find(&'^');
+        let missing = grid.find(&'#');
+
+        // then
+        assert_eq!(found, Some(Point::new(1, 1)));
+        assert_eq!(missing, None);
+    }
+
+    #[test]
+    fn index_mut_test() {
+        // given
+        let mut grid: Grid<char> = "..\n..".parse().unwrap();
+
+        // when
+        grid[Point::new(1, 0)] = '#';
+
+        // then
+        assert_eq!(grid.to_string(), ".#\n..");
+    }
+
+    #[test]
+    fn new_checks_cell_count_test() {
+        assert!(Grid::new(2, 2, vec![1, 2, 3, 4]).is_ok());
+        assert_eq!(Grid::new(2, 2, vec![1, 2, 3]), Err(GridError::CellCount { width: 2, height: 2, cells: 3 }));
+    }
+
+    #[test]
+    fn zero_width_grid_has_no_rows_test() {
+        // given
+        let empty = Grid::<u8>::filled(0, 0, 0);
+        let no_columns = Grid::<u8>::new(0, 3, vec![]).unwrap();
+
+        // then
+        assert_eq!(empty.rows().count(), 0);
+        assert_eq!(empty.to_string(), "");
+        assert_eq!(no_columns.rows().count(), 0);
+        assert_eq!(no_columns.to_string(), "");
+    }
+}
@@ -0,0 +1,317 @@
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Cursor, Read};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::crypto::{self, Key};
+
+/// Directory holding `dayN.txt` inputs, consulted before a day's own `resources/input.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Path given on the command line to read the input from stdin instead of a file.
+pub const STDIN: &str = "-";
+
+/// Resolves where a day's puzzle input is read from.
+///
+/// Locations are tried in order: the explicit path (or stdin for `-`), `$AOC_INPUT_DIR/dayN.txt`,
+/// and finally `resources/input.txt` next to the day's `Cargo.toml`. The first existing file wins,
+/// where an encrypted `input.txt.enc` stands in for a missing `input.txt` and is decrypted with the
+/// [`Key`] from the environment or the workspace's `input.key`.
+#[derive(Debug, Clone, Default)]
+pub struct InputLocator {
+    explicit: Option<PathBuf>,
+    input_dir: Option<PathBuf>,
+    manifest_dir: Option<PathBuf>,
+}
+
+impl InputLocator {
+    /// Locator picking up `AOC_INPUT_DIR` from the environment.
+    pub fn from_env() -> Self {
+        Self {
+            input_dir: env::var_os(INPUT_DIR_VAR).map(PathBuf::from),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_explicit(mut self, path: Option<impl Into<PathBuf>>) -> Self {
+        self.explicit = path.map(Into::into);
+        self
+    }
+
+    pub fn with_input_dir(mut self, dir: Option<impl Into<PathBuf>>) -> Self {
+        self.input_dir = dir.map(Into::into);
+        self
+    }
+
+    pub fn with_manifest_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.manifest_dir = Some(dir.into());
+        self
+    }
+
+    pub fn load(&self, day: u8) -> anyhow::Result<String> {
+        self.load_with_stdin(day, io::stdin())
+    }
+
+    fn load_with_stdin(&self, day: u8, mut stdin: impl Read) -> anyhow::Result<String> {
+        if self.reads_stdin() {
+            let mut input = String::new();
+            stdin.read_to_string(&mut input)?;
+            return Ok(input);
+        }
+        self.find(day, |path| self.read(path))
+    }
+
+    /// Opens the input found where [`load`](Self::load) would read it, to be read a line at a time.
+    /// An encrypted input is decrypted as a whole first.
+    pub fn open(&self, day: u8) -> anyhow::Result<Box<dyn BufRead>> {
+        if self.reads_stdin() {
+            return Ok(Box::new(io::stdin().lock()));
+        }
+        self.find(day, |path| {
+            if path.to_string_lossy().ends_with(crypto::ENCRYPTED_SUFFIX) {
+                return self.read(path).map(|input| input.map(|input| Box::new(Cursor::new(input)) as Box<dyn BufRead>));
+            }
+            match File::open(path) {
+                Ok(file) => Some(Ok(Box::new(BufReader::new(file)))),
+                Err(err) => unless_missing(err, path),
+            }
+        })
+    }
+
+    fn reads_stdin(&self) -> bool {
+        self.explicit.as_ref().is_some_and(|explicit| explicit.as_os_str() == STDIN)
+    }
+
+    /// Applies `read` to the explicit path, or to every candidate and its encrypted form in turn,
+    /// until one of them exists.
+    fn find<T>(&self, day: u8, read: impl Fn(&Path) -> Option<anyhow::Result<T>>) -> anyhow::Result<T> {
+        if let Some(explicit) = &self.explicit {
+            return read(explicit).unwrap_or_else(|| Err(InputNotFound { day, tried: vec![explicit.clone()] }.into()));
+        }
+
+        let tried = self.candidates(day);
+        tried.iter()
+            .find_map(|path| read(path).or_else(|| read(&crypto::encrypted_path(path))))
+            .unwrap_or_else(|| Err(InputNotFound { day, tried }.into()))
+    }
+
+    /// Reads the input at `path` if it exists, decrypting it when its name ends in `.enc`.
+    fn read(&self, path: &Path) -> Option<anyhow::Result<String>> {
+        if path.to_string_lossy().ends_with(crypto::ENCRYPTED_SUFFIX) {
+            let encrypted = match fs::read(path) {
+                Ok(encrypted) => encrypted,
+                Err(err) => return unless_missing(err, path),
+            };
+            let workspace_root = self.manifest_dir.as_deref().and_then(Path::parent);
+            return Some(Key::locate(workspace_root)
+                .and_then(|key| key.decrypt(&encrypted))
+                .with_context(|| format!("failed to decrypt {}", path.display())));
+        }
+        match fs::read_to_string(path) {
+            Ok(input) => Some(Ok(input)),
+            Err(err) => unless_missing(err, path),
+        }
+    }
+
+    /// Where a downloaded input should be stored so that [`load`](Self::load) finds it: the input
+    /// directory when one is set, otherwise the day's `resources/input.txt`.
+    pub fn cache_path(&self, day: u8) -> Option<PathBuf> {
+        self.candidates(day).into_iter().next()
+    }
+
+    fn candidates(&self, day: u8) -> Vec<PathBuf> {
+        let in_input_dir = self.input_dir.as_ref().map(|dir| dir.join(format!("day{day}.txt")));
+        let in_manifest_dir = self.manifest_dir.as_ref().map(|dir| dir.join("resources").join("input.txt"));
+        in_input_dir.into_iter().chain(in_manifest_dir).collect()
+    }
+}
+
+/// `None` when nothing is at `path`, so that the next location is tried, otherwise the error.
+fn unless_missing<T>(err: io::Error, path: &Path) -> Option<anyhow::Result<T>> {
+    (err.kind() != io::ErrorKind::NotFound).then(|| Err(anyhow::Error::new(err).context(format!("failed to read {}", path.display()))))
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct InputNotFound {
+    pub day: u8,
+    pub tried: Vec<PathBuf>,
+}
+
+impl Display for InputNotFound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no readable input for day {}", self.day)?;
+        if self.tried.is_empty() {
+            return write!(f, " (no locations configured, pass --input or set {INPUT_DIR_VAR})");
+        }
+        write!(f, ", tried (each also with an `{}` suffix):", crypto::ENCRYPTED_SUFFIX)?;
+        for path in &self.tried {
+            write!(f, "\n  - {}", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputNotFound {}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn explicit_path_wins_test() {
+        // given
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        fs::write(dir.join("custom.txt"), "explicit").unwrap();
+        fs::write(dir.join("day1.txt"), "input dir").unwrap();
+        let locator = InputLocator::default()
+            .with_explicit(Some(dir.join("custom.txt")))
+            .with_input_dir(Some(dir));
+
+        // when
+        let input = locator.load(1).unwrap();
+
+        // then
+        assert_eq!(input, "explicit");
+    }
+
+    #[test]
+    fn dash_reads_stdin_test() {
+        // given
+        let locator = InputLocator::default().with_explicit(Some(STDIN));
+
+        // when
+        let input = locator.load_with_stdin(1, Cursor::new("from stdin")).unwrap();
+
+        // then
+        assert_eq!(input, "from stdin");
+    }
+
+    #[test]
+    fn input_dir_before_manifest_dir_test() {
+        // given
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        fs::create_dir_all(dir.join("resources")).unwrap();
+        fs::write(dir.join("resources/input.txt"), "manifest dir").unwrap();
+        fs::write(dir.join("day2.txt"), "input dir").unwrap();
+        let locator = InputLocator::default()
+            .with_input_dir(Some(dir))
+            .with_manifest_dir(dir);
+
+        // when
+        let day2 = locator.load(2).unwrap();
+        let day3 = locator.load(3).unwrap();
+
+        // then
+        assert_eq!(day2, "input dir");
+        assert_eq!(day3, "manifest dir");
+    }
+
+    #[test]
+    fn missing_input_lists_tried_locations_test() {
+        // given
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let locator = InputLocator::default()
+            .with_input_dir(Some(dir.join("inputs")))
+            .with_manifest_dir(dir.join("day4"));
+
+        // when
+        let err = locator.load(4).unwrap_err();
+
+        // then
+        let err = err.downcast::<InputNotFound>().unwrap();
+        assert_eq!(err.tried, vec![
+            dir.join("inputs").join("day4.txt"),
+            dir.join("day4").join("resources").join("input.txt"),
+        ]);
+        assert!(err.to_string().contains("day4.txt"));
+    }
+
+    #[test]
+    fn unreadable_input_is_reported_test() {
+        // given
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        fs::create_dir_all(dir.join("resources")).unwrap();
+        fs::write(dir.join("resources/input.txt"), "manifest dir").unwrap();
+        fs::write(dir.join("day6.txt"), [0xff, 0xfe]).unwrap();
+        let locator = InputLocator::default()
+            .with_input_dir(Some(dir))
+            .with_manifest_dir(dir);
+
+        // when
+        let err = locator.load(6).unwrap_err();
+
+        // then
+        assert!(err.to_string().contains("failed to read"));
+        assert!(err.to_string().contains("day6.txt"));
+    }
+
+    #[test]
+    fn open_reads_what_load_reads_test() {
+        // given
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let key = Key::generate().unwrap();
+        fs::write(dir.join("day1.txt"), "3   4\n4   3\n").unwrap();
+        fs::write(dir.join("day2.txt.enc"), key.encrypt("7 6 4 2 1\n").unwrap()).unwrap();
+        fs::write(dir.join(crypto::DEFAULT_KEY_FILE), key.to_string()).unwrap();
+        let locator = InputLocator::default()
+            .with_input_dir(Some(dir))
+            .with_manifest_dir(dir.join("day"));
+
+        // when
+        let read = |day| {
+            let mut input = String::new();
+            locator.open(day).unwrap().read_to_string(&mut input).unwrap();
+            input
+        };
+
+        // then
+        assert_eq!(read(1), locator.load(1).unwrap());
+        assert_eq!(read(2), "7 6 4 2 1\n");
+        assert!(locator.open(3).err().unwrap().downcast::<InputNotFound>().is_ok());
+    }
+
+    #[test]
+    fn cache_path_test() {
+        // given
+        let with_input_dir = InputLocator::default()
+            .with_input_dir(Some("inputs"))
+            .with_manifest_dir("day5");
+        let without_input_dir = InputLocator::default().with_manifest_dir("day5");
+
+        // then
+        assert_eq!(with_input_dir.cache_path(5), Some(PathBuf::from("inputs/day5.txt")));
+        assert_eq!(without_input_dir.cache_path(5), Some(PathBuf::from("day5/resources/input.txt")));
+        assert_eq!(InputLocator::default().cache_path(5), None);
+    }
+
+    #[test]
+    fn encrypted_input_is_decrypted_test() {
+        // given
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let key = Key::generate().unwrap();
+        fs::create_dir_all(dir.join("day7/resources")).unwrap();
+        fs::write(dir.join("day7/resources/input.txt.enc"), key.encrypt("190: 10 19\n").unwrap()).unwrap();
+        fs::write(dir.join(crypto::DEFAULT_KEY_FILE), key.to_string()).unwrap();
+        let locator = InputLocator::default().with_manifest_dir(dir.join("day7"));
+
+        // when
+        let input = locator.load(7).unwrap();
+        fs::remove_file(dir.join(crypto::DEFAULT_KEY_FILE)).unwrap();
+        let without_key = locator.load(7);
+
+        // then
+        assert_eq!(input, "190: 10 19\n");
+        assert!(format!("{:#}", without_key.unwrap_err()).contains("failed to decrypt"));
+    }
+}
@@ -0,0 +1,15 @@
+mod answer;
+pub mod crypto;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+mod options;
+pub mod parse;
+mod solution;
+
+pub use answer::Answer;
+pub use geometry::{Direction4, Direction8, Point};
+pub use grid::Grid;
+pub use options::Options;
+pub use solution::{exit_code, print_answers, register, DayArgs, Part, Registration, Run, Solution};
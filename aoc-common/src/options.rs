@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use anyhow::Context;
+
+/// Day specific `KEY=VALUE` settings changing how a day parses or solves its input, such as
+/// `distance=squared` for day 1.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Options(BTreeMap<String, String>);
+
+impl Options {
+    /// Reads `KEY=VALUE` pairs, a later value replacing an earlier one for the same key.
+    pub fn parse<S: AsRef<str>>(pairs: impl IntoIterator<Item=S>) -> anyhow::Result<Self> {
+        let mut options = BTreeMap::new();
+        for pair in pairs {
+            let pair = pair.as_ref();
+            let (key, value) = pair.split_once('=').with_context(|| format!("expected KEY=VALUE, got {pair:?}"))?;
+            options.insert(key.to_owned(), value.to_owned());
+        }
+        Ok(Self(options))
+    }
+
+    /// Fails on the first key that is not one of the `known` options of `day`.
+    pub fn check(&self, day: u8, known: &[&str]) -> anyhow::Result<()> {
+        match self.0.keys().find(|key| !known.contains(&key.as_str())) {
+            Some(key) if known.is_empty() => anyhow::bail!("day {day} takes no options, got {key:?}"),
+            Some(key) => anyhow::bail!("day {day} has no option {key:?}, expected one of {}", known.join(", ")),
+            None => Ok(()),
+        }
+    }
+
+    /// The value of `key` parsed as a `T`, or `T::default()` when it is not set.
+    pub fn get_or_default<T: FromStr<Err=anyhow::Error> + Default>(&self, key: &str) -> anyhow::Result<T> {
+        match self.0.get(key) {
+            Some(value) => value.parse().with_context(|| format!("invalid value for option {key}")),
+            None => Ok(T::default()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, Eq, PartialEq)]
+    struct Level(u8);
+
+    impl FromStr for Level {
+        type Err = anyhow::Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Ok(Self(s.parse()?))
+        }
+    }
+
+    #[test]
+    fn parse_test() {
+        // when
+        let options = Options::parse(["level=3", "name=a=b", "level=4"]).unwrap();
+
+        // then
+        assert_eq!(options.get_or_default::<Level>("level").unwrap(), Level(4));
+        assert_eq!(options.get_or_default::<Level>("missing").unwrap(), Level(0));
+        assert_eq!(options.0["name"], "a=b");
+        assert_eq!(Options::parse(["level"]).unwrap_err().to_string(), "expected KEY=VALUE, got \"level\"");
+    }
+
+    #[test]
+    fn invalid_value_test() {
+        // given
+        let options = Options::parse(["level=high"]).unwrap();
+
+        // when
+        let err = options.get_or_default::<Level>("level").unwrap_err();
+
+        // then
+        assert_eq!(err.to_string(), "invalid value for option level");
+    }
+
+    #[test]
+    fn check_test() {
+        // given
+        let options = Options::parse(["level=3"]).unwrap();
+
+        // then
+        assert!(options.check(1, &["level"]).is_ok());
+        assert!(Options::default().check(1, &[]).is_ok());
+        assert_eq!(options.check(1, &["name", "size"]).unwrap_err().to_string(), "day 1 has no option \"level\", expected one of name, size");
+        assert_eq!(options.check(2, &[]).unwrap_err().to_string(), "day 2 takes no options, got \"level\"");
+    }
+}
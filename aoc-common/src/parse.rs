@@ -0,0 +1,266 @@
+use std::fmt::{Display, Formatter};
+
+use nom::character::complete::line_ending;
+use nom::combinator::{eof, opt};
+use nom::error::{ContextError, ErrorKind, FromExternalError};
+use nom::sequence::pair;
+
+/// nom result whose error keeps enough detail to be turned into a [`ParseError`].
+pub type IResult<'a, O> = nom::IResult<&'a str, O, Error<'a>>;
+
+/// nom error type recording what was expected where, and inside which [`nom::error::context`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Error<'a> {
+    pub input: &'a str,
+    pub expected: Expected,
+    pub context: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Expected {
+    Char(char),
+    EndOfLine,
+    Kind(ErrorKind),
+    /// Input was recognised but rejected by a conversion such as `str::parse`.
+    Valid(String),
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Char(c) => write!(f, "`{c}`"),
+            Expected::EndOfLine => write!(f, "end of line"),
+            Expected::Kind(ErrorKind::Digit) => write!(f, "a number"),
+            Expected::Kind(ErrorKind::Eof) => write!(f, "end of input"),
+            Expected::Kind(ErrorKind::CrLf) => write!(f, "a line ending"),
+            Expected::Kind(ErrorKind::Space | ErrorKind::MultiSpace) => write!(f, "whitespace"),
+            Expected::Kind(kind) => write!(f, "{}", kind.description().to_lowercase()),
+            Expected::Valid(reason) => write!(f, "a valid value ({reason})"),
+        }
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Error<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Self { input, expected: Expected::Kind(kind), context: None }
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        Self { input, expected: Expected::Char(c), context: None }
+    }
+
+    fn or(self, other: Self) -> Self {
+        // the alternative that got further is the more helpful one to report
+        if other.input.len() < self.input.len() { other } else { self }
+    }
+}
+
+impl<'a, E: Display> FromExternalError<&'a str, E> for Error<'a> {
+    fn from_external_error(input: &'a str, _kind: ErrorKind, e: E) -> Self {
+        Self { input, expected: Expected::Valid(e.to_string()), context: None }
+    }
+}
+
+impl<'a> ContextError<&'a str> for Error<'a> {
+    fn add_context(_input: &'a str, ctx: &'static str, mut other: Self) -> Self {
+        other.context.get_or_insert(ctx);
+        other
+    }
+}
+
+/// Parses one item per line with `parser`, stopping before an empty line or the end of input.
+///
+/// Unlike `separated_list1(line_ending, ..)` a line that does not parse completely is an error
+/// rather than the silent end of the list.
+pub fn lines<'a, O>(mut parser: impl FnMut(&'a str) -> IResult<'a, O>) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    move |mut input: &'a str| {
+        let mut items = vec![];
+        loop {
+            let (rest, item) = parser(input).map_err(to_failure)?;
+            items.push(item);
+            if rest.is_empty() {
+                return Ok((rest, items));
+            }
+            let Ok((next, _)) = line_ending::<_, Error>(rest) else {
+                return Err(nom::Err::Failure(Error { input: rest, expected: Expected::EndOfLine, context: None }));
+            };
+            if next.is_empty() || line_ending::<_, Error>(next).is_ok() {
+                return Ok((rest, items));
+            }
+            input = next;
+        }
+    }
+}
+
+fn to_failure(err: nom::Err<Error>) -> nom::Err<Error> {
+    match err {
+        nom::Err::Error(e) => nom::Err::Failure(e),
+        err => err,
+    }
+}
+
+/// Runs `parser` over the whole `input`, which may end with a single line ending (`\n` or `\r\n`).
+///
+/// Anything `parser` rejects or leaves behind is reported with the line and column it starts at.
+pub fn parse_all<'a, O>(input: &'a str, mut parser: impl FnMut(&'a str) -> IResult<'a, O>) -> Result<O, ParseError> {
+    let (rest, output) = parser(input).map_err(|err| ParseError::new(input, err))?;
+    pair(opt(line_ending), eof)(rest).map_err(|err| ParseError::new(input, err))?;
+    Ok(output)
+}
+
+/// Input that could not be parsed, pointing at the offending line and column (both 1-based).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub content: String,
+    pub expected: String,
+    pub context: Option<&'static str>,
+}
+
+impl ParseError {
+    fn new(input: &str, err: nom::Err<Error>) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => Self::at(input, &e),
+            nom::Err::Incomplete(_) => Self::at(input, &Error { input: "", expected: Expected::Kind(ErrorKind::Eof), context: None }),
+        }
+    }
+
+    fn at(input: &str, err: &Error) -> Self {
+        let offset = input.len() - err.input.len();
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            content: input[line_start..].lines().next().unwrap_or_default().to_owned(),
+            expected: err.expected.to_string(),
+            context: err.context,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: expected {}", self.line, self.column, self.expected)?;
+        if let Some(context) = self.context {
+            write!(f, " in {context}")?;
+        }
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f)?;
+        writeln!(f, "{} | {}", self.line, self.content)?;
+        write!(f, "{gutter} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use nom::character::complete::{char, digit1, line_ending};
+    use nom::error::context;
+    use nom::multi::separated_list1;
+    use nom::sequence::separated_pair;
+
+    use super::*;
+
+    fn numbers(input: &str) -> IResult<'_, Vec<&str>> {
+        separated_list1(line_ending, digit1)(input)
+    }
+
+    fn ranges(input: &str) -> IResult<'_, Vec<(&str, &str)>> {
+        lines(context("range", separated_pair(digit1, char('-'), digit1)))(input)
+    }
+
+    #[test]
+    fn parse_all_test() {
+        assert_eq!(parse_all("1\n2", numbers), Ok(vec!["1", "2"]));
+        assert_eq!(parse_all("1\n2\n", numbers), Ok(vec!["1", "2"]));
+        assert_eq!(parse_all("1\r\n2\r\n", numbers), Ok(vec!["1", "2"]));
+    }
+
+    #[test]
+    fn parse_all_names_first_bad_line_test() {
+        // given
+        let input = "1\n2\nthree\n4\n";
+
+        // when
+        let err = parse_all(input, numbers).unwrap_err();
+
+        // then
+        assert_eq!((err.line, err.column, err.content.as_str()), (3, 1, "three"));
+    }
+
+    #[test]
+    fn parse_all_allows_only_one_trailing_newline_test() {
+        // given
+        let input = "1\n2\n\n";
+
+        // when
+        let err = parse_all(input, numbers).unwrap_err();
+
+        // then
+        assert_eq!((err.line, err.expected.as_str()), (3, "end of input"));
+    }
+
+    #[test]
+    fn lines_reports_expected_token_test() {
+        // given
+        let input = "1-2\n3-4\n56+7\n8-9";
+
+        // when
+        let err = parse_all(input, ranges).unwrap_err();
+
+        // then
+        assert_eq!(err, ParseError {
+            line: 3,
+            column: 3,
+            content: "56+7".to_owned(),
+            expected: "`-`".to_owned(),
+            context: Some("range"),
+        });
+        assert_eq!(err.to_string(), "3:3: expected `-` in range
+3 | 56+7
+  |   ^");
+    }
+
+    #[test]
+    fn lines_rejects_trailing_garbage_on_line_test() {
+        // given
+        let input = "1-2\n3-4 x\n";
+
+        // when
+        let err = parse_all(input, ranges).unwrap_err();
+
+        // then
+        assert_eq!((err.line, err.column, err.expected.as_str()), (2, 4, "end of line"));
+    }
+
+    #[test]
+    fn lines_stops_at_empty_line_test() {
+        // given
+        let input = "1-2\n3-4\n\n5";
+
+        // when
+        let (rest, items) = ranges(input).unwrap();
+
+        // then
+        assert_eq!(items, vec![("1", "2"), ("3", "4")]);
+        assert_eq!(rest, "\n\n5");
+    }
+
+    #[test]
+    fn parse_all_reports_failure_of_first_line_test() {
+        // given
+        let input = "x-1\n2-3\n";
+
+        // when
+        let err = parse_all(input, ranges).unwrap_err();
+
+        // then
+        assert_eq!((err.line, err.column, err.expected.as_str()), (1, 1, "a number"));
+    }
+}
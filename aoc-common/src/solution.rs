@@ -0,0 +1,293 @@
+use std::env;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::fmt::{Display, Formatter};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use rand::rngs::StdRng;
+
+use crate::input::InputLocator;
+use crate::{Answer, Options};
+
+/// A single day's puzzle: how its input is parsed and how both parts are solved.
+pub trait Solution {
+    const DAY: u8;
+
+    /// `CARGO_MANIFEST_DIR` of the day's crate, used to find its bundled `resources/input.txt`.
+    const MANIFEST_DIR: &'static str;
+
+    type Input;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Answer>;
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer>;
+
+    /// Random valid input whose scale grows with `size`, for stress tests and benchmarks.
+    fn generate(_size: usize, _rng: &mut StdRng) -> anyhow::Result<String> {
+        anyhow::bail!("day {} has no input generator", Self::DAY)
+    }
+
+    fn solve(input: &Self::Input, part: Part) -> anyhow::Result<Answer> {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+
+    /// Keys of the [`Options`] the day understands, none by default.
+    const OPTIONS: &'static [&'static str] = &[];
+
+    /// [`parse`](Self::parse) honouring the day's [`OPTIONS`](Self::OPTIONS).
+    fn parse_with_options(input: &str, _options: &Options) -> anyhow::Result<Self::Input> {
+        Self::parse(input)
+    }
+
+    /// [`solve`](Self::solve) honouring the day's [`OPTIONS`](Self::OPTIONS).
+    fn solve_with_options(input: &Self::Input, part: Part, _options: &Options) -> anyhow::Result<Answer> {
+        Self::solve(input, part)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => anyhow::bail!("part must be 1 or 2, got {value}"),
+        }
+    }
+}
+
+/// Outcome of running one part of a day, with the time spent parsing and solving.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Run {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Type-erased handle to a [`Solution`], so days can be kept together in one registry.
+#[derive(Debug, Copy, Clone)]
+pub struct Registration {
+    pub day: u8,
+    pub manifest_dir: &'static str,
+    run: fn(&str, Part, &Options) -> anyhow::Result<Run>,
+    generate: fn(usize, &mut StdRng) -> anyhow::Result<String>,
+}
+
+impl Registration {
+    pub fn load_input(&self, explicit: Option<&Path>) -> anyhow::Result<String> {
+        InputLocator::from_env()
+            .with_explicit(explicit)
+            .with_manifest_dir(self.manifest_dir)
+            .load(self.day)
+    }
+
+    pub fn run(&self, input: &str, part: Part) -> anyhow::Result<Run> {
+        self.run_with(input, part, &Options::default())
+    }
+
+    /// Runs `part` with day specific `options`, failing on options the day does not know.
+    pub fn run_with(&self, input: &str, part: Part, options: &Options) -> anyhow::Result<Run> {
+        (self.run)(input, part, options)
+    }
+
+    pub fn generate(&self, size: usize, rng: &mut StdRng) -> anyhow::Result<String> {
+        (self.generate)(size, rng)
+    }
+}
+
+pub fn register<S: Solution>() -> Registration {
+    Registration { day: S::DAY, manifest_dir: S::MANIFEST_DIR, run: run::<S>, generate: S::generate }
+}
+
+fn run<S: Solution>(input: &str, part: Part, options: &Options) -> anyhow::Result<Run> {
+    options.check(S::DAY, S::OPTIONS)?;
+    let start = Instant::now();
+    let parsed = S::parse_with_options(input, options)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = S::solve_with_options(&parsed, part, options)?;
+    let solve_time = start.elapsed();
+
+    Ok(Run { answer, parse_time, solve_time })
+}
+
+/// Command line of a `dayN` binary: an optional input location, `-` for stdin, and any number of
+/// `KEY=VALUE` [`Options`].
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct DayArgs {
+    pub input: Option<PathBuf>,
+    pub options: Options,
+}
+
+impl DayArgs {
+    /// Arguments containing a `=` are options, the single one without is the input.
+    pub fn parse(args: impl IntoIterator<Item=OsString>) -> anyhow::Result<Self> {
+        let mut input = None;
+        let mut options = vec![];
+        for arg in args {
+            match arg.to_str() {
+                Some(option) if option.contains('=') => options.push(option.to_owned()),
+                _ if input.is_none() => input = Some(PathBuf::from(arg)),
+                _ => anyhow::bail!("unexpected argument {:?}, the input was already given", arg.to_string_lossy()),
+            }
+        }
+        Ok(Self { input, options: Options::parse(options)? })
+    }
+
+    /// Where the input of `S` is read from, the explicit input first.
+    pub fn locator<S: Solution>(&self) -> InputLocator {
+        InputLocator::from_env()
+            .with_explicit(self.input.clone())
+            .with_manifest_dir(S::MANIFEST_DIR)
+    }
+}
+
+/// Entry point shared by every `dayN` binary: solves both parts of the day's input and prints them.
+///
+/// The arguments are read as [`DayArgs`].
+pub fn print_answers<S: Solution>() -> ExitCode {
+    exit_code(DayArgs::parse(env::args_os().skip(1)).and_then(|args| solve_and_print::<S>(&args)))
+}
+
+fn solve_and_print<S: Solution>(args: &DayArgs) -> anyhow::Result<()> {
+    args.options.check(S::DAY, S::OPTIONS)?;
+    let input = args.locator::<S>().load(S::DAY)?;
+    let input = S::parse_with_options(&input, &args.options).context("failed to parse the input")?;
+    for part in Part::ALL {
+        let answer = S::solve_with_options(&input, part, &args.options).with_context(|| format!("failed to solve part {part}"))?;
+        println!("Day {} Part {part} answer: {answer}", S::DAY);
+    }
+    Ok(())
+}
+
+/// Exit code of a binary that ended with `result`, printing the error the way `aoc` does.
+pub fn exit_code(result: anyhow::Result<()>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        const DAY: u8 = 42;
+        const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+        type Input = String;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.trim().to_owned())
+        }
+
+        fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+            Ok(input.clone().into())
+        }
+
+        fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+            Ok(input.chars().rev().collect::<String>().into())
+        }
+    }
+
+    #[test]
+    fn registration_runs_selected_part_test() {
+        // given
+        let registration = register::<Echo>();
+
+        // when
+        let part1 = registration.run("abc\n", Part::One).unwrap();
+        let part2 = registration.run("abc\n", Part::Two).unwrap();
+
+        // then
+        assert_eq!(registration.day, 42);
+        assert_eq!(part1.answer, "abc");
+        assert_eq!(part2.answer, "cba");
+    }
+
+    #[test]
+    fn registration_rejects_unknown_options_test() {
+        // given
+        let registration = register::<Echo>();
+        let options = Options::parse(["reverse=yes"]).unwrap();
+
+        // when
+        let result = registration.run_with("abc\n", Part::One, &options);
+
+        // then
+        assert_eq!(result.unwrap_err().to_string(), "day 42 takes no options, got \"reverse\"");
+    }
+
+    #[test]
+    fn registration_without_generator_test() {
+        // given
+        let registration = register::<Echo>();
+
+        // when
+        let result = registration.generate(10, &mut rand::SeedableRng::seed_from_u64(0));
+
+        // then
+        assert_eq!(result.unwrap_err().to_string(), "day 42 has no input generator");
+    }
+
+    #[test]
+    fn day_args_test() {
+        // given
+        let args = ["-", "distance=squared", "lengths=pad"].map(OsString::from);
+
+        // when
+        let parsed = DayArgs::parse(args).unwrap();
+        let options_only = DayArgs::parse([OsString::from("distance=squared")]).unwrap();
+        let two_inputs = DayArgs::parse(["a.txt", "b.txt"].map(OsString::from));
+
+        // then
+        assert_eq!(parsed.input, Some(PathBuf::from("-")));
+        assert_eq!(parsed.options, Options::parse(["distance=squared", "lengths=pad"]).unwrap());
+        assert_eq!(options_only.input, None);
+        assert_eq!(two_inputs.unwrap_err().to_string(), "unexpected argument \"b.txt\", the input was already given");
+    }
+
+    #[test]
+    fn part_try_from_test() {
+        assert_eq!(Part::try_from(1).unwrap(), Part::One);
+        assert_eq!(Part::try_from(2).unwrap(), Part::Two);
+        assert!(Part::try_from(3).is_err());
+    }
+}
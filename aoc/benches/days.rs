@@ -0,0 +1,66 @@
+use std::env;
+use std::hint::black_box;
+use std::time::Duration;
+
+use aoc_common::examples;
+use aoc_common::input::InputLocator;
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Benchmarks parsing and both parts of `S`, separately, on its real input and on every example in its
+/// `resources/examples`, plus a generated input when `AOC_BENCH_SIZE` is set (see `aoc gen`).
+/// A real input that cannot be loaded is skipped with a warning.
+///
+/// Run a single day with e.g. `cargo bench -p aoc -- day6/`.
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+    // some parts take seconds per iteration on the real input
+    group.sample_size(10).warm_up_time(Duration::from_millis(500));
+
+    let mut inputs = vec![];
+    match InputLocator::from_env().with_manifest_dir(S::MANIFEST_DIR).load(S::DAY) {
+        Ok(input) => inputs.push(("input".to_owned(), input)),
+        Err(err) => eprintln!("warning: not benchmarking day {} on its real input: {err:#}", S::DAY),
+    }
+    if let Some(size) = env::var_os("AOC_BENCH_SIZE") {
+        let size: usize = size.to_str()
+            .and_then(|size| size.parse().ok())
+            .unwrap_or_else(|| panic!("AOC_BENCH_SIZE should be a number, got {size:?}"));
+        let input = S::generate(size, &mut StdRng::seed_from_u64(0))
+            .unwrap_or_else(|err| panic!("day {} should generate an input of size {size}: {err:#}", S::DAY));
+        inputs.push((format!("generated{size}"), input));
+    }
+    for example in examples::load(S::MANIFEST_DIR).expect("example manifest should be valid") {
+        let input = example.input().expect("example should be readable");
+        inputs.push((example.name, input));
+    }
+
+    for (name, input) in &inputs {
+        let input = input.as_str();
+        group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+            b.iter(|| S::parse(black_box(input)))
+        });
+        let parsed = S::parse(input).expect("benchmark input should parse");
+        group.bench_with_input(BenchmarkId::new("part1", name), &parsed, |b, parsed| {
+            b.iter(|| S::part1(black_box(parsed)))
+        });
+        group.bench_with_input(BenchmarkId::new("part2", name), &parsed, |b, parsed| {
+            b.iter(|| S::part2(black_box(parsed)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(days,
+    bench_day::<day1::Day1>,
+    bench_day::<day2::Day2>,
+    bench_day::<day3::Day3>,
+    bench_day::<day4::Day4>,
+    bench_day::<day5::Day5>,
+    bench_day::<day6::Day6>,
+    bench_day::<day7::Day7>,
+    bench_day::<day8::Day8>,
+);
+criterion_main!(days);
@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use aoc_common::{Answer, Part};
+use serde::Deserialize;
+
+/// Default location of the recorded answers, at the workspace root.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("answers.toml")
+}
+
+/// Accepted answers keyed as `[dayN] partP = "answer"`.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, String>>);
+
+impl Answers {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed to read answers from {}", path.display()))?;
+        content.parse()
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<Answer> {
+        let answer = self.0.get(&format!("day{day}"))?.get(&format!("part{part}"))?;
+        let Ok(answer) = answer.parse();
+        Some(answer)
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(toml::from_str(s)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_test() {
+        // given
+        let answers: Answers = r#"
+[day1]
+part1 = "11"
+part2 = "31"
+
+[day6]
+part1 = "41"
+"#.parse().unwrap();
+
+        // then
+        assert_eq!(answers.get(1, Part::One), Some(Answer::Unsigned(11)));
+        assert_eq!(answers.get(1, Part::Two), Some(Answer::Unsigned(31)));
+        assert_eq!(answers.get(6, Part::Two), None);
+        assert_eq!(answers.get(2, Part::One), None);
+    }
+
+    #[test]
+    fn workspace_answers_are_valid_test() {
+        // when
+        let answers = Answers::load(&default_path()).unwrap();
+
+        // then
+        assert!(answers.get(1, Part::One).is_some());
+    }
+}
@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use aoc_common::{Answer, Part};
+
+use crate::config::Config;
+
+const USER_AGENT: &str = "advent-of-code-2024 runner (ureq)";
+
+/// HTTP client for the puzzle server, authenticated with the session cookie.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    year: u16,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> anyhow::Result<Self> {
+        Ok(Self {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+            base_url: config.base_url.trim_end_matches('/').to_owned(),
+            year: config.year,
+            session: config.session()?.to_owned(),
+        })
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{}/day/{day}{path}", self.base_url, self.year)
+    }
+
+    /// Downloads the puzzle input of `day`.
+    pub fn input(&self, day: u8) -> anyhow::Result<String> {
+        let url = self.url(day, "/input");
+        let response = self.agent.get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(404, _) => anyhow::anyhow!("the input of day {day} is not available yet ({url})"),
+                ureq::Error::Status(400 | 401, _) => anyhow::anyhow!("{url} rejected the session token, it may have expired"),
+                ureq::Error::Status(status, _) => anyhow::anyhow!("failed to download the input of day {day}: {url} answered with status {status}"),
+                err => anyhow::anyhow!("failed to download the input of day {day}: {err}"),
+            })?;
+        Ok(response.into_string()?)
+    }
+
+    /// Submits `answer` for `part` of `day` and returns the page the server responds with.
+    pub fn submit(&self, day: u8, part: Part, answer: &Answer) -> anyhow::Result<String> {
+        let url = self.url(day, "/answer");
+        let response = self.agent.post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", &answer.to_string())])
+            .map_err(|err| anyhow::anyhow!("failed to submit day {day} part {part}: {err}"))?;
+        Ok(response.into_string()?)
+    }
+}
@@ -0,0 +1,144 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde::Deserialize;
+
+/// Overrides the location of the config file, `aoc.toml` at the workspace root by default.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+/// Session cookie of the adventofcode.com account, taking precedence over the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Server to talk to instead of adventofcode.com, taking precedence over the config file.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_YEAR: u16 = 2024;
+
+/// Settings for talking to the puzzle server, read from `aoc.toml` and the environment.
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// year = 2024
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub year: u16,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self { session: None, base_url: DEFAULT_BASE_URL.to_owned(), year: DEFAULT_YEAR }
+    }
+}
+
+impl Config {
+    /// Reads the config file, if there is one, and applies the environment overrides.
+    pub fn load() -> anyhow::Result<Self> {
+        let config = Self::read(env::var_os(CONFIG_VAR).map(PathBuf::from).as_deref(), &default_path())?;
+        Ok(config.with_overrides(|var| env::var(var).ok()))
+    }
+
+    /// Reads the `explicit` config file, or the one at `default` when there is one.
+    fn read(explicit: Option<&Path>, default: &Path) -> anyhow::Result<Self> {
+        let path = explicit.unwrap_or(default);
+        match fs::read_to_string(path) {
+            Ok(content) => content.parse().with_context(|| format!("invalid config {}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound && explicit.is_none() => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("failed to read config {}", path.display())),
+        }
+    }
+
+    fn with_overrides(mut self, var: impl Fn(&str) -> Option<String>) -> Self {
+        if let Some(session) = var(SESSION_VAR) {
+            self.session = Some(session);
+        }
+        if let Some(base_url) = var(BASE_URL_VAR) {
+            self.base_url = base_url;
+        }
+        self
+    }
+
+    pub fn session(&self) -> anyhow::Result<&str> {
+        self.session.as_deref()
+            .map(str::trim)
+            .filter(|session| !session.is_empty())
+            .with_context(|| format!("no session token, set {SESSION_VAR} or `session` in aoc.toml"))
+    }
+}
+
+impl std::str::FromStr for Config {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(toml::from_str(s)?)
+    }
+}
+
+fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("aoc.toml")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_test() {
+        // when
+        let config: Config = "session = \"abc\"\nyear = 2023".parse().unwrap();
+
+        // then
+        assert_eq!(config, Config { session: Some("abc".to_owned()), base_url: DEFAULT_BASE_URL.to_owned(), year: 2023 });
+        assert!("sesion = \"abc\"".parse::<Config>().is_err());
+    }
+
+    #[test]
+    fn environment_overrides_file_test() {
+        // given
+        let config: Config = "session = \"from file\"\nbase_url = \"http://file\"".parse().unwrap();
+
+        // when
+        let config = config.with_overrides(|var| (var == SESSION_VAR).then(|| "from env".to_owned()));
+
+        // then
+        assert_eq!(config.session().unwrap(), "from env");
+        assert_eq!(config.base_url, "http://file");
+    }
+
+    #[test]
+    fn read_test() {
+        // given
+        let dir = tempfile::tempdir().unwrap();
+        let present = dir.path().join("aoc.toml");
+        fs::write(&present, "year = 2023").unwrap();
+        let absent = dir.path().join("missing.toml");
+
+        // when
+        let explicit = Config::read(Some(&present), &absent).unwrap();
+        let default = Config::read(None, &present).unwrap();
+        let no_file = Config::read(None, &absent).unwrap();
+        let explicit_missing = Config::read(Some(&absent), &present).unwrap_err();
+        let unreadable = Config::read(None, dir.path()).unwrap_err();
+
+        // then
+        assert_eq!((explicit.year, default.year), (2023, 2023));
+        assert_eq!(no_file, Config::default());
+        assert_eq!(explicit_missing.to_string(), format!("failed to read config {}", absent.display()));
+        assert_eq!(unreadable.to_string(), format!("failed to read config {}", dir.path().display()));
+    }
+
+    #[test]
+    fn missing_session_test() {
+        // given
+        let config: Config = "session = \" \"".parse().unwrap();
+
+        // then
+        assert!(config.session().unwrap_err().to_string().contains(SESSION_VAR));
+    }
+}
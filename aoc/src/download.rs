@@ -0,0 +1,123 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use aoc_common::crypto;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Source {
+    /// The input was already on disk and nothing was requested.
+    Cache,
+    Download,
+}
+
+/// Makes sure the input of `day` is stored at `cache`, calling `download` only when it is not.
+///
+/// An encrypted `.enc` sibling counts as cached as well, while an empty file, as left by `aoc new`,
+/// does not.
+pub fn fetch(cache: &Path, day: u8, download: impl FnOnce(u8) -> anyhow::Result<String>) -> anyhow::Result<Source> {
+    let non_empty = |path: &Path| fs::metadata(path).is_ok_and(|meta| meta.len() > 0);
+    if non_empty(cache) || non_empty(&crypto::encrypted_path(cache)) {
+        return Ok(Source::Cache);
+    }
+    let input = download(day)?;
+    anyhow::ensure!(!input.is_empty(), "the server returned an empty input for day {day}");
+    if let Some(dir) = cache.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(cache, input).with_context(|| format!("failed to write {}", cache.display()))?;
+    Ok(Source::Download)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::Client;
+    use crate::stub;
+
+    use super::*;
+
+    #[test]
+    fn downloads_once_then_uses_cache_test() {
+        // given
+        let (config, requests) = stub::serve(vec![(200, "3   4\n4   3\n")]);
+        let client = Client::new(&config).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join("inputs").join("day1.txt");
+
+        // when
+        let first = fetch(&cache, 1, |day| client.input(day)).unwrap();
+        let second = fetch(&cache, 1, |day| client.input(day)).unwrap();
+
+        // then
+        assert_eq!((first, second), (Source::Download, Source::Cache));
+        assert_eq!(fs::read_to_string(&cache).unwrap(), "3   4\n4   3\n");
+        let request = requests.recv().unwrap();
+        assert_eq!((request.method.as_str(), request.url.as_str()), ("GET", "/2024/day/1/input"));
+        assert_eq!(request.cookie.as_deref(), Some("session=s3cret"));
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[test]
+    fn empty_cache_file_is_replaced_test() {
+        // given
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join("input.txt");
+        fs::write(&cache, "").unwrap();
+
+        // when
+        let source = fetch(&cache, 9, |_| Ok("1 2\n".to_owned())).unwrap();
+
+        // then
+        assert_eq!(source, Source::Download);
+        assert_eq!(fs::read_to_string(&cache).unwrap(), "1 2\n");
+    }
+
+    #[test]
+    fn encrypted_cache_is_not_downloaded_again_test() {
+        // given
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join("input.txt");
+        fs::write(crypto::encrypted_path(&cache), "aoc-enc1...").unwrap();
+
+        // when
+        let source = fetch(&cache, 2, |_| panic!("should not download")).unwrap();
+
+        // then
+        assert_eq!(source, Source::Cache);
+        assert!(!cache.exists());
+    }
+
+    #[test]
+    fn unavailable_input_is_not_cached_test() {
+        // given
+        let (config, _requests) = stub::serve(vec![(404, "Please don't repeatedly request this endpoint before it unlocks!")]);
+        let client = Client::new(&config).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join("day25.txt");
+
+        // when
+        let err = fetch(&cache, 25, |day| client.input(day)).unwrap_err();
+
+        // then
+        assert!(err.to_string().contains("not available yet"));
+        assert!(!cache.exists());
+    }
+
+    #[test]
+    fn server_errors_are_not_blamed_on_the_session_test() {
+        // given
+        let (config, _requests) = stub::serve(vec![(401, "Unauthorized"), (500, "Internal Server Error")]);
+        let client = Client::new(&config).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join("day3.txt");
+
+        // when
+        let rejected = fetch(&cache, 3, |day| client.input(day)).unwrap_err();
+        let failed = fetch(&cache, 3, |day| client.input(day)).unwrap_err();
+
+        // then
+        assert!(rejected.to_string().contains("rejected the session token"));
+        assert!(failed.to_string().ends_with("answered with status 500"));
+        assert!(!cache.exists());
+    }
+}
@@ -0,0 +1,113 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use aoc_common::crypto::{self, Key};
+
+/// Default key file, `input.key` at the workspace root.
+pub fn default_key_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(crypto::DEFAULT_KEY_FILE)
+}
+
+/// Writes a new random key to `path`, which must not exist yet.
+pub fn keygen(path: &Path) -> anyhow::Result<()> {
+    anyhow::ensure!(!path.exists(), "{} already exists, inputs encrypted with it would become unreadable", path.display());
+    fs::write(path, format!("{}\n", Key::generate()?))
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+    }
+    Ok(())
+}
+
+/// Encrypts the plaintext input at `plain` to its `.enc` sibling, removing the plaintext unless
+/// `keep` is set. Returns false when there is no plaintext to encrypt.
+pub fn encrypt(key: &Key, plain: &Path, keep: bool) -> anyhow::Result<bool> {
+    let input = match fs::read_to_string(plain) {
+        Ok(input) => input,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(err) => return Err(err).with_context(|| format!("failed to read {}", plain.display())),
+    };
+    let encrypted = crypto::encrypted_path(plain);
+    fs::write(&encrypted, key.encrypt(&input)?)
+        .with_context(|| format!("failed to write {}", encrypted.display()))?;
+    if !keep {
+        fs::remove_file(plain)?;
+    }
+    Ok(true)
+}
+
+/// Writes the plaintext of the `.enc` sibling of `plain` back to `plain`, keeping the encrypted
+/// file. Returns false when there is no encrypted input.
+pub fn decrypt(key: &Key, plain: &Path) -> anyhow::Result<bool> {
+    let encrypted = crypto::encrypted_path(plain);
+    let content = match fs::read(&encrypted) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(err) => return Err(err).with_context(|| format!("failed to read {}", encrypted.display())),
+    };
+    let input = key.decrypt(&content).with_context(|| format!("failed to decrypt {}", encrypted.display()))?;
+    fs::write(plain, input).with_context(|| format!("failed to write {}", plain.display()))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_then_decrypt_test() {
+        // given
+        let dir = tempfile::tempdir().unwrap();
+        let key_path = dir.path().join("input.key");
+        let plain = dir.path().join("input.txt");
+        fs::write(&plain, "7 6 4 2 1\n").unwrap();
+        keygen(&key_path).unwrap();
+        let key = Key::locate(Some(dir.path())).unwrap();
+
+        // when
+        let encrypted = encrypt(&key, &plain, false).unwrap();
+        let plain_after_encrypt = plain.exists();
+        let decrypted = decrypt(&key, &plain).unwrap();
+
+        // then
+        assert!(encrypted && decrypted);
+        assert!(!plain_after_encrypt);
+        assert_eq!(fs::read_to_string(&plain).unwrap(), "7 6 4 2 1\n");
+        assert!(crypto::encrypted_path(&plain).exists());
+        assert!(keygen(&key_path).is_err());
+    }
+
+    #[test]
+    fn nothing_to_do_test() {
+        // given
+        let dir = tempfile::tempdir().unwrap();
+        let key = Key::generate().unwrap();
+        let plain = dir.path().join("input.txt");
+
+        // then
+        assert!(!encrypt(&key, &plain, true).unwrap());
+        assert!(!decrypt(&key, &plain).unwrap());
+    }
+
+    #[test]
+    fn unreadable_input_is_reported_test() {
+        // given
+        let dir = tempfile::tempdir().unwrap();
+        let key = Key::generate().unwrap();
+        let plain = dir.path().join("input.txt");
+        fs::write(&plain, [0xff, 0xfe]).unwrap();
+        fs::create_dir(crypto::encrypted_path(&plain)).unwrap();
+
+        // when
+        let encrypt_err = encrypt(&key, &plain, true).unwrap_err();
+        let decrypt_err = decrypt(&key, &plain).unwrap_err();
+
+        // then
+        assert_eq!(encrypt_err.to_string(), format!("failed to read {}", plain.display()));
+        assert_eq!(decrypt_err.to_string(), format!("failed to read {}", crypto::encrypted_path(&plain).display()));
+    }
+}
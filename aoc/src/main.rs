@@ -0,0 +1,379 @@
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc_common::input::InputLocator;
+use aoc_common::{Options, Part, Registration, Solution};
+use clap::{Args, Parser, Subcommand};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use report::{Format, Record, Status};
+
+mod answers;
+mod client;
+mod config;
+mod download;
+mod inputs;
+mod registry;
+mod report;
+mod scaffold;
+#[cfg(test)]
+mod stub;
+mod submit;
+mod verify;
+mod watch;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve one day, or every registered day, and print the answers with timings.
+    Run(RunArgs),
+    /// Run every part against its real input and compare it with the answers recorded in `answers.toml`.
+    Verify(VerifyArgs),
+    /// Rebuild and re-run a day whenever its sources or resources change.
+    Watch(WatchArgs),
+    /// Download puzzle inputs that are not stored locally yet.
+    Fetch(FetchArgs),
+    /// Manage encrypted copies of the puzzle inputs.
+    Inputs(InputsArgs),
+    /// Solve one part and submit the answer, unless earlier submissions show it cannot be right.
+    Submit(SubmitArgs),
+    /// Generate the crate for a new day from the template and register it with the runner.
+    New(NewArgs),
+    /// Print a random input for a day, e.g. to stress test it with `aoc run --input -`.
+    Gen(GenArgs),
+    /// Break day 1's answers down into the pairs and ids they are summed from.
+    Explain(ExplainArgs),
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    /// Day to run.
+    #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Run only this part; both parts are run when omitted.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Run every registered day.
+    #[arg(short, long)]
+    all: bool,
+    /// Read the input from this file instead of the default locations, `-` for stdin.
+    #[arg(short, long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+    /// Day specific setting such as `distance=squared` for day 1, may be repeated.
+    #[arg(short, long = "option", value_name = "KEY=VALUE", conflicts_with = "all")]
+    options: Vec<String>,
+    /// How to print the results.
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+    /// Verify only this day.
+    #[arg(short, long)]
+    day: Option<u8>,
+    /// Recorded answers to compare against, `answers.toml` at the workspace root by default.
+    #[arg(long)]
+    answers: Option<PathBuf>,
+    /// How to print the results.
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+}
+
+#[derive(Debug, Args)]
+struct WatchArgs {
+    /// Day to watch.
+    #[arg(short, long)]
+    day: u8,
+}
+
+#[derive(Debug, Args)]
+struct FetchArgs {
+    #[command(flatten)]
+    days: DaysArgs,
+}
+
+#[derive(Debug, Args)]
+struct InputsArgs {
+    #[command(subcommand)]
+    command: InputsCommand,
+}
+
+#[derive(Debug, Subcommand)]
+enum InputsCommand {
+    /// Create a new random key in `input.key` at the workspace root.
+    Keygen,
+    /// Encrypt plaintext inputs to `.enc` files, removing the plaintext.
+    Encrypt {
+        #[command(flatten)]
+        days: DaysArgs,
+        /// Keep the plaintext input next to the encrypted one.
+        #[arg(long)]
+        keep: bool,
+    },
+    /// Restore plaintext inputs from their `.enc` files.
+    Decrypt {
+        #[command(flatten)]
+        days: DaysArgs,
+    },
+}
+
+#[derive(Debug, Args)]
+struct DaysArgs {
+    /// Only this day.
+    #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Every registered day.
+    #[arg(short, long)]
+    all: bool,
+}
+
+impl DaysArgs {
+    fn registrations(&self) -> anyhow::Result<Vec<Registration>> {
+        Ok(match self.day {
+            Some(day) => vec![registry::find(day)?],
+            None => registry::days(),
+        })
+    }
+}
+
+#[derive(Debug, Args)]
+struct SubmitArgs {
+    /// Day to submit.
+    #[arg(short, long)]
+    day: u8,
+    /// Part to submit.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Submit this answer instead of solving the part.
+    #[arg(short, long)]
+    answer: Option<String>,
+    /// Read the input from this file instead of the default locations, `-` for stdin.
+    #[arg(short, long, conflicts_with = "answer")]
+    input: Option<PathBuf>,
+    /// File recording every submission, `submissions.jsonl` at the workspace root by default.
+    #[arg(long)]
+    history: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+struct NewArgs {
+    /// Day to create.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Debug, Args)]
+struct GenArgs {
+    /// Day to generate an input for.
+    #[arg(short, long)]
+    day: u8,
+    /// Scale of the input: the number of lines for most days, the side of the map for days 4, 6
+    /// and 8, the number of updates for day 5 and of operands per equation for day 7.
+    #[arg(short, long, value_parser = clap::value_parser!(u64).range(1..))]
+    size: u64,
+    /// Seed making the input reproducible; a random one is used when omitted.
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+#[derive(Debug, Args)]
+struct ExplainArgs {
+    /// Read the input from this file instead of the default locations, `-` for stdin.
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    /// How many of the largest distances and similarity contributions to list.
+    #[arg(short, long, default_value_t = 5)]
+    top: usize,
+    /// Day 1 setting, of which only `lengths` changes the explanation, may be repeated.
+    #[arg(short, long = "option", value_name = "KEY=VALUE")]
+    options: Vec<String>,
+    /// How to print the explanation, as tables or as a JSON object.
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Watch(args) => registry::find(args.day).and_then(|day| watch::watch(&day, &scaffold::default_root())),
+        Command::Fetch(args) => fetch(args),
+        Command::Inputs(args) => manage_inputs(args),
+        Command::Submit(args) => submit(args),
+        Command::New(args) => new(args),
+        Command::Gen(args) => generate(args),
+        Command::Explain(args) => explain(args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: RunArgs) -> anyhow::Result<()> {
+    let days = match args.day {
+        Some(day) => vec![registry::find(day)?],
+        None => registry::days(),
+    };
+    let parts = match args.part {
+        Some(part) => vec![Part::try_from(part)?],
+        None => Part::ALL.to_vec(),
+    };
+    let options = Options::parse(&args.options)?;
+
+    let records: Vec<_> = days.iter()
+        .flat_map(|day| run_day(day, &parts, args.input.as_deref(), &options))
+        .collect();
+    report::write(&mut io::stdout().lock(), args.format, &records, None)?;
+
+    let failed = records.iter().filter(|r| r.status.is_failure()).count();
+    if failed > 0 {
+        anyhow::bail!("{failed} of {} parts could not be solved", records.len());
+    }
+    Ok(())
+}
+
+fn run_day(day: &Registration, parts: &[Part], input: Option<&Path>, options: &Options) -> Vec<Record> {
+    let input = day.load_input(input);
+    parts.iter()
+        .map(|&part| {
+            let result = match &input {
+                Ok(input) => day.run_with(input, part, options),
+                Err(err) => Err(anyhow::anyhow!("{err:#}")),
+            };
+            Record::solved(day.day, part, result)
+        })
+        .collect()
+}
+
+fn verify(args: VerifyArgs) -> anyhow::Result<()> {
+    let answers = answers::Answers::load(&args.answers.unwrap_or_else(answers::default_path))?;
+    let days = match args.day {
+        Some(day) => vec![registry::find(day)?],
+        None => registry::days(),
+    };
+
+    let records: Vec<Record> = verify::verify(&days, &answers).into_iter().map(Record::from).collect();
+    let failed = records.iter().filter(|r| r.status.is_failure()).count();
+    let missing = records.iter().filter(|r| r.status == Status::Missing).count();
+    let summary = format!("{} passed, {failed} failed, {missing} missing", records.len() - failed - missing);
+    report::write(&mut io::stdout().lock(), args.format, &records, Some(&summary))?;
+
+    if failed > 0 {
+        anyhow::bail!("{failed} of {} checks did not match the recorded answers", records.len());
+    }
+    Ok(())
+}
+
+fn fetch(args: FetchArgs) -> anyhow::Result<()> {
+    let config = config::Config::load()?;
+    for day in args.days.registrations()? {
+        let cache = InputLocator::from_env()
+            .with_manifest_dir(day.manifest_dir)
+            .cache_path(day.day)
+            .expect("a manifest dir is always a cache location");
+        match download::fetch(&cache, day.day, |d| client::Client::new(&config)?.input(d))? {
+            download::Source::Cache => println!("Day {}: already stored at {}", day.day, cache.display()),
+            download::Source::Download => println!("Day {}: downloaded to {}", day.day, cache.display()),
+        }
+    }
+    Ok(())
+}
+
+fn manage_inputs(args: InputsArgs) -> anyhow::Result<()> {
+    let (days, encrypt, keep) = match args.command {
+        InputsCommand::Keygen => {
+            let path = inputs::default_key_path();
+            inputs::keygen(&path)?;
+            println!("Created {}, keep it out of version control", path.display());
+            return Ok(());
+        }
+        InputsCommand::Encrypt { days, keep } => (days, true, keep),
+        InputsCommand::Decrypt { days } => (days, false, true),
+    };
+    let key = aoc_common::crypto::Key::locate(inputs::default_key_path().parent())?;
+
+    for day in days.registrations()? {
+        let plain = InputLocator::from_env()
+            .with_manifest_dir(day.manifest_dir)
+            .cache_path(day.day)
+            .expect("a manifest dir is always an input location");
+        let done = if encrypt { inputs::encrypt(&key, &plain, keep)? } else { inputs::decrypt(&key, &plain)? };
+        match (done, encrypt) {
+            (true, true) => println!("Day {}: encrypted {}", day.day, plain.display()),
+            (true, false) => println!("Day {}: decrypted {}", day.day, plain.display()),
+            (false, _) => println!("Day {}: nothing to do for {}", day.day, plain.display()),
+        }
+    }
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> anyhow::Result<()> {
+    let config = config::Config::load()?;
+    let part = Part::try_from(args.part)?;
+    let answer = match args.answer {
+        Some(answer) => {
+            let Ok(answer) = answer.parse();
+            answer
+        }
+        None => {
+            let day = registry::find(args.day)?;
+            day.run(&day.load_input(args.input.as_deref())?, part)?.answer
+        }
+    };
+    let mut history = submit::History::load(&args.history.unwrap_or_else(submit::default_history_path))?;
+
+    let client = client::Client::new(&config)?;
+    let verdict = submit::submit(&mut history, args.day, part, answer.clone(), submit::now(), |answer| client.submit(args.day, part, answer))?;
+    println!("Day {} Part {part} answer {answer}: {verdict}", args.day);
+    if verdict != submit::Verdict::Correct {
+        anyhow::bail!("the answer was not accepted");
+    }
+    Ok(())
+}
+
+fn new(args: NewArgs) -> anyhow::Result<()> {
+    let dir = scaffold::new_day(&scaffold::default_root(), args.day)?;
+    println!("Created {}, put the puzzle input in resources/input.txt", dir.display());
+    Ok(())
+}
+
+fn generate(args: GenArgs) -> anyhow::Result<()> {
+    let day = registry::find(args.day)?;
+    let mut rng = match args.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let input = day.generate(usize::try_from(args.size)?, &mut rng)?;
+    io::stdout().lock().write_all(input.as_bytes())?;
+    Ok(())
+}
+
+fn explain(args: ExplainArgs) -> anyhow::Result<()> {
+    let options = Options::parse(&args.options)?;
+    options.check(1, &["lengths"])?;
+    let input = registry::find(1)?.load_input(args.input.as_deref())?;
+    let explanation = day1::explain::explain(&day1::Day1::parse_with_options(&input, &options)?, args.top)?;
+    let mut out = io::stdout().lock();
+    match args.format {
+        Format::Text => write!(out, "{explanation}")?,
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut out, &explanation)?;
+            writeln!(out)?;
+        }
+        Format::Csv => anyhow::bail!("an explanation is printed as text or json"),
+    }
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use aoc_common::{register, Registration};
+
+/// Every solved day, in calendar order. New days are added here to become runnable from `aoc`.
+pub fn days() -> Vec<Registration> {
+    vec![
+        register::<day1::Day1>(),
+        register::<day2::Day2>(),
+        register::<day3::Day3>(),
+        register::<day4::Day4>(),
+        register::<day5::Day5>(),
+        register::<day6::Day6>(),
+        register::<day7::Day7>(),
+        register::<day8::Day8>(),
+    ]
+}
+
+pub fn find(day: u8) -> anyhow::Result<Registration> {
+    days().into_iter()
+        .find(|r| r.day == day)
+        .ok_or_else(|| anyhow::anyhow!("day {day} has no registered solution"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Part;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn days_are_unique_and_ordered_test() {
+        // given
+        let days: Vec<_> = days().iter().map(|r| r.day).collect();
+
+        // then
+        assert!(days.windows(2).all(|w| w[0] < w[1]), "{days:?}");
+    }
+
+    #[test]
+    fn find_unknown_day_test() {
+        // when
+        let result = find(26);
+
+        // then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn generated_inputs_are_solvable_test() {
+        // given
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for day in days() {
+            for size in [1, 2, 5] {
+                // when
+                let input = day.generate(size, &mut rng)
+                    .unwrap_or_else(|err| panic!("day {} failed to generate an input of size {size}: {err:#}", day.day));
+                let runs = Part::ALL.map(|part| day.run(&input, part));
+
+                // then
+                for (part, run) in Part::ALL.into_iter().zip(runs) {
+                    assert!(run.is_ok(), "day {} part {part} failed on generated input of size {size}: {:#}", day.day, run.unwrap_err());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::time::Duration;
+
+use aoc_common::{Answer, Part, Run};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::verify::{self, Check};
+
+/// How the runner prints its results.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// One human readable line per part.
+    #[default]
+    Text,
+    /// A JSON array of records.
+    Json,
+    /// A header line followed by one record per line.
+    Csv,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    /// Solved without comparing against a recorded answer.
+    Solved,
+    Pass,
+    Fail,
+    Missing,
+    Error,
+}
+
+impl Status {
+    pub fn is_failure(self) -> bool {
+        matches!(self, Status::Fail | Status::Error)
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Status::Solved => "solved",
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Missing => "missing",
+            Status::Error => "error",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Outcome of one day and part, as printed in every [`Format`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<Answer>,
+    pub expected: Option<Answer>,
+    pub parse_time_ns: Option<u64>,
+    pub solve_time_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn solved(day: u8, part: Part, result: anyhow::Result<Run>) -> Self {
+        match result {
+            Ok(run) => Self::new(day, part, Status::Solved, Some(run), None),
+            Err(err) => Self { error: Some(format!("{err:#}")), ..Self::new(day, part, Status::Error, None, None) },
+        }
+    }
+
+    fn new(day: u8, part: Part, status: Status, run: Option<Run>, expected: Option<Answer>) -> Self {
+        let nanos = |d: Duration| u64::try_from(d.as_nanos()).unwrap_or(u64::MAX);
+        Self {
+            day,
+            part: part.number(),
+            status,
+            parse_time_ns: run.as_ref().map(|run| nanos(run.parse_time)),
+            solve_time_ns: run.as_ref().map(|run| nanos(run.solve_time)),
+            answer: run.map(|run| run.answer),
+            expected,
+            error: None,
+        }
+    }
+
+    fn times(&self) -> Option<(Duration, Duration)> {
+        Some((Duration::from_nanos(self.parse_time_ns?), Duration::from_nanos(self.solve_time_ns?)))
+    }
+}
+
+impl From<Check> for Record {
+    fn from(check: Check) -> Self {
+        let (status, expected, error) = match check.status {
+            verify::Status::Pass => (Status::Pass, None, None),
+            verify::Status::Fail { expected } => (Status::Fail, Some(expected), None),
+            verify::Status::Missing => (Status::Missing, None, None),
+            verify::Status::Error(err) => (Status::Error, None, Some(err)),
+        };
+        Self { error, ..Self::new(check.day, check.part, status, check.run, expected) }
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let answer = self.answer.as_ref().map(Answer::to_string).unwrap_or_default();
+        match self.status {
+            Status::Solved => write!(f, "Day {} Part {} answer: {answer}", self.day, self.part)?,
+            Status::Pass => write!(f, "Day {} Part {}: PASS {answer}", self.day, self.part)?,
+            Status::Fail => {
+                let expected = self.expected.as_ref().map(Answer::to_string).unwrap_or_default();
+                write!(f, "Day {} Part {}: FAIL expected {expected}, got {answer}", self.day, self.part)?
+            }
+            Status::Missing => write!(f, "Day {} Part {}: MISSING got {answer}", self.day, self.part)?,
+            Status::Error => write!(f, "Day {} Part {}: ERROR {}", self.day, self.part, self.error.as_deref().unwrap_or_default())?,
+        }
+        if let Some((parse_time, solve_time)) = self.times() {
+            write!(f, " (parse: {parse_time:?}, solve: {solve_time:?})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `records` in `format`, text output ending with `summary` when one is given.
+pub fn write(out: &mut impl Write, format: Format, records: &[Record], summary: Option<&str>) -> anyhow::Result<()> {
+    match format {
+        Format::Text => {
+            for record in records {
+                writeln!(out, "{record}")?;
+            }
+            if let Some(summary) = summary {
+                writeln!(out, "{summary}")?;
+            }
+        }
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, records)?;
+            writeln!(out)?;
+        }
+        Format::Csv => {
+            writeln!(out, "day,part,status,answer,expected,parse_time_ns,solve_time_ns,error")?;
+            for record in records {
+                let fields = [
+                    record.day.to_string(),
+                    record.part.to_string(),
+                    record.status.to_string(),
+                    record.answer.as_ref().map(Answer::to_string).unwrap_or_default(),
+                    record.expected.as_ref().map(Answer::to_string).unwrap_or_default(),
+                    record.parse_time_ns.map(|ns| ns.to_string()).unwrap_or_default(),
+                    record.solve_time_ns.map(|ns| ns.to_string()).unwrap_or_default(),
+                    record.error.clone().unwrap_or_default(),
+                ];
+                writeln!(out, "{}", fields.map(|field| csv_field(&field)).join(","))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        let run = Run { answer: Answer::Unsigned(11), parse_time: Duration::from_micros(3), solve_time: Duration::from_nanos(1500) };
+        vec![
+            Record::solved(1, Part::One, Ok(run)),
+            Record::solved(1, Part::Two, Err(anyhow::anyhow!("bad input, \"line 2\""))),
+        ]
+    }
+
+    fn output(format: Format) -> String {
+        let mut out = vec![];
+        write(&mut out, format, &records(), Some("1 solved")).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn text_test() {
+        assert_eq!(output(Format::Text), "Day 1 Part 1 answer: 11 (parse: 3µs, solve: 1.5µs)
+Day 1 Part 2: ERROR bad input, \"line 2\"
+1 solved
+");
+    }
+
+    #[test]
+    fn csv_test() {
+        assert_eq!(output(Format::Csv), "day,part,status,answer,expected,parse_time_ns,solve_time_ns,error
+1,1,solved,11,,3000,1500,
+1,2,error,,,,,\"bad input, \"\"line 2\"\"\"
+");
+    }
+
+    #[test]
+    fn json_test() {
+        // when
+        let json: serde_json::Value = serde_json::from_str(&output(Format::Json)).unwrap();
+
+        // then
+        assert_eq!(json, serde_json::json!([
+            {
+                "day": 1, "part": 1, "status": "solved",
+                "answer": {"type": "unsigned", "value": 11}, "expected": null,
+                "parse_time_ns": 3000, "solve_time_ns": 1500, "error": null,
+            },
+            {
+                "day": 1, "part": 2, "status": "error",
+                "answer": null, "expected": null,
+                "parse_time_ns": null, "solve_time_ns": null, "error": "bad input, \"line 2\"",
+            },
+        ]));
+    }
+
+    #[test]
+    fn from_check_test() {
+        // given
+        let check = Check { day: 3, part: Part::Two, run: None, status: verify::Status::Fail { expected: Answer::Unsigned(48) } };
+
+        // when
+        let record = Record::from(check);
+
+        // then
+        assert_eq!(record.status, Status::Fail);
+        assert_eq!(record.expected, Some(Answer::Unsigned(48)));
+        assert!(record.status.is_failure());
+    }
+}
@@ -0,0 +1,131 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+/// Files of a new day's crate, relative to its directory, with `{{day}}` standing for the day number.
+const TEMPLATES: [(&str, &str); 7] = [
+    ("Cargo.toml", include_str!("../templates/day/Cargo.toml.tmpl")),
+    ("build.rs", include_str!("../templates/day/build.rs.tmpl")),
+    ("src/main.rs", include_str!("../templates/day/main.rs.tmpl")),
+    ("src/lib.rs", include_str!("../templates/day/lib.rs.tmpl")),
+    ("resources/input.txt", ""),
+    ("resources/examples/example.txt", ""),
+    ("resources/examples/examples.toml", include_str!("../templates/day/examples.toml.tmpl")),
+];
+
+/// Formats the line registering a day.
+type Entry = fn(u8) -> String;
+
+/// Places in the runner listing every day, relative to the workspace root, and the line for a day.
+const REGISTRATIONS: [(&str, Entry); 3] = [
+    ("aoc/Cargo.toml", |day| format!("day{day} = {{ path = \"../day{day}\" }}")),
+    ("aoc/src/registry.rs", |day| format!("register::<day{day}::Day{day}>(),")),
+    ("aoc/benches/days.rs", |day| format!("bench_day::<day{day}::Day{day}>,")),
+];
+
+/// Workspace root this binary was built from.
+pub fn default_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("aoc lives in the workspace").to_path_buf()
+}
+
+/// Creates the `day{day}` crate under `root` from the templates and registers it with the runner.
+///
+/// Nothing is written unless every registration point was found.
+pub fn new_day(root: &Path, day: u8) -> anyhow::Result<PathBuf> {
+    let dir = root.join(format!("day{day}"));
+    anyhow::ensure!(!dir.exists(), "{} already exists", dir.display());
+
+    let registrations = REGISTRATIONS.iter()
+        .map(|(file, entry)| {
+            let path = root.join(file);
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            let content = register(&content, day, *entry)
+                .with_context(|| format!("failed to register day {day} in {}", path.display()))?;
+            Ok((path, content))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    for (file, template) in TEMPLATES {
+        let path = dir.join(file);
+        fs::create_dir_all(path.parent().expect("template paths have a parent"))?;
+        fs::write(&path, template.replace("{{day}}", &day.to_string()))
+            .with_context(|| format!("failed to write {}", path.display()))?;
+    }
+    for (path, content) in registrations {
+        fs::write(&path, content).with_context(|| format!("failed to write {}", path.display()))?;
+    }
+    Ok(dir)
+}
+
+/// Inserts `entry(day)` after the entry of the closest earlier day, keeping its indentation.
+fn register(content: &str, day: u8, entry: Entry) -> anyhow::Result<String> {
+    let registered = |line: &str| (1..=25).find(|&d| line.trim() == entry(d));
+    let mut lines: Vec<&str> = content.lines().collect();
+    anyhow::ensure!(!lines.iter().any(|line| registered(line) == Some(day)), "day {day} is already registered");
+
+    let (index, previous) = lines.iter().enumerate()
+        .rfind(|(_, line)| registered(line).is_some_and(|d| d < day))
+        .with_context(|| format!("no entry of an earlier day like `{}`", entry(1)))?;
+    let indent = &previous[..previous.len() - previous.trim_start().len()];
+    let line = format!("{indent}{}", entry(day));
+    lines.insert(index + 1, &line);
+
+    let mut content = lines.join("\n");
+    content.push('\n');
+    Ok(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_test() {
+        // given
+        let content = "vec![
+    register::<day1::Day1>(),
+    register::<day2::Day2>(),
+    register::<day12::Day12>(),
+]
+";
+
+        // when
+        let result = register(content, 9, REGISTRATIONS[1].1).unwrap();
+
+        // then
+        assert_eq!(result, "vec![
+    register::<day1::Day1>(),
+    register::<day2::Day2>(),
+    register::<day9::Day9>(),
+    register::<day12::Day12>(),
+]
+");
+        assert!(register(&result, 9, REGISTRATIONS[1].1).is_err());
+        assert!(register("vec![]", 9, REGISTRATIONS[1].1).is_err());
+    }
+
+    #[test]
+    fn new_day_test() {
+        // given
+        let root = tempfile::tempdir().unwrap();
+        for (file, _) in REGISTRATIONS {
+            fs::create_dir_all(root.path().join(file).parent().unwrap()).unwrap();
+            fs::copy(default_root().join(file), root.path().join(file)).unwrap();
+        }
+
+        // when
+        let dir = new_day(root.path(), 25).unwrap();
+
+        // then
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day25;") && lib.contains("const DAY: u8 = 25;"));
+        assert!(dir.join("resources/input.txt").is_file());
+        let registry = fs::read_to_string(root.path().join("aoc/src/registry.rs")).unwrap();
+        assert!(registry.contains("        register::<day25::Day25>(),\n    ]"));
+        let manifest = fs::read_to_string(root.path().join("aoc/Cargo.toml")).unwrap();
+        assert!(manifest.contains("day25 = { path = \"../day25\" }"));
+        assert!(new_day(root.path(), 25).is_err());
+    }
+}
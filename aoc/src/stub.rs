@@ -0,0 +1,43 @@
+//! Local HTTP server standing in for adventofcode.com in tests.
+
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+use tiny_http::{Response, Server};
+
+use crate::config::Config;
+
+/// A request as seen by the stub server.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// Serves `responses` (status and body) to the next requests on a local port, in order.
+///
+/// Returns a config pointing at the server and the requests it received.
+pub fn serve(responses: Vec<(u16, &'static str)>) -> (Config, Receiver<Request>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let config = Config {
+        session: Some("s3cret".to_owned()),
+        base_url: format!("http://{}/", server.server_addr()),
+        ..Config::default()
+    };
+    let (sender, requests) = mpsc::channel();
+    thread::spawn(move || {
+        for (status, body) in responses {
+            let Ok(mut request) = server.recv() else { return };
+            let mut content = String::new();
+            request.as_reader().read_to_string(&mut content).unwrap();
+            let cookie = request.headers().iter()
+                .find(|h| h.field.equiv("Cookie"))
+                .map(|h| h.value.to_string());
+            sender.send(Request { method: request.method().to_string(), url: request.url().to_owned(), cookie, body: content }).unwrap();
+            request.respond(Response::from_string(body).with_status_code(status)).unwrap();
+        }
+    });
+    (config, requests)
+}
@@ -0,0 +1,284 @@
+use std::fmt::{Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use aoc_common::{Answer, Part};
+use serde::{Deserialize, Serialize};
+
+/// Default location of the submission history, at the workspace root.
+pub fn default_history_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("submissions.jsonl")
+}
+
+/// The server's judgement of a submitted answer.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "verdict", rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint in which direction.
+    Wrong,
+    /// Submitted too soon after the previous attempt; nothing was judged.
+    Wait { seconds: u64 },
+    /// The part was solved before, so the server did not judge the answer.
+    AlreadySolved,
+    /// A response the parser does not recognise, kept for inspection.
+    Unknown { response: String },
+}
+
+impl Verdict {
+    /// Reads the verdict out of the HTML page the server answers a submission with.
+    pub fn parse(response: &str) -> Self {
+        let text = response.to_lowercase();
+        if text.contains("that's the right answer") {
+            Verdict::Correct
+        } else if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else if text.contains("not the right answer") {
+            Verdict::Wrong
+        } else if let Some(seconds) = wait_seconds(&text) {
+            Verdict::Wait { seconds }
+        } else if text.contains("don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            let article = response.split("<article>").nth(1).and_then(|a| a.split("</article>").next());
+            Verdict::Unknown { response: article.unwrap_or(response).trim().to_owned() }
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+/// Parses the `you have 1m 5s left to wait` part of a throttled response.
+fn wait_seconds(text: &str) -> Option<u64> {
+    let wait = text.split("you have ").nth(1)?.split(" left to wait").next()?;
+    wait.split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            match unit {
+                "s" => Some(number),
+                "m" => Some(number * 60),
+                "h" => Some(number * 3600),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait { seconds } => write!(f, "submitted too soon, wait {seconds}s"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::Unknown { response } => write!(f, "unrecognised response: {response}"),
+        }
+    }
+}
+
+/// One submission and the server's verdict, as stored in the history file.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    /// Seconds since the Unix epoch.
+    pub submitted_at: u64,
+    #[serde(flatten)]
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted so far, one JSON object per line.
+#[derive(Debug, Clone)]
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Reads the history at `path`; a missing file is an empty history.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let attempts = match fs::read_to_string(path) {
+            Ok(content) => content.lines()
+                .filter(|line| !line.trim().is_empty())
+                .enumerate()
+                .map(|(i, line)| serde_json::from_str(line)
+                    .with_context(|| format!("invalid attempt on line {} of {}", i + 1, path.display())))
+                .collect::<anyhow::Result<_>>()?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+            Err(err) => return Err(err).with_context(|| format!("failed to read {}", path.display())),
+        };
+        Ok(Self { path: path.to_owned(), attempts })
+    }
+
+    /// Explains why submitting `answer` now cannot be right or would be throttled, if it would.
+    pub fn refusal(&self, day: u8, part: Part, answer: &Answer, now: u64) -> Option<String> {
+        let attempts: Vec<_> = self.attempts.iter().filter(|a| a.day == day && a.part == part.number()).collect();
+        if let Some(correct) = attempts.iter().find(|a| a.verdict == Verdict::Correct) {
+            return Some(format!("day {day} part {part} was already solved with {}", correct.answer));
+        }
+        if let Some(wrong) = attempts.iter().find(|a| a.verdict.is_wrong() && a.answer == *answer) {
+            return Some(format!("{answer} was already submitted and judged {}", wrong.verdict));
+        }
+        if let Some(n) = answer.as_integer() {
+            let bound = attempts.iter().find(|a| match (&a.verdict, a.answer.as_integer()) {
+                (Verdict::TooHigh, Some(high)) => n >= high,
+                (Verdict::TooLow, Some(low)) => n <= low,
+                _ => false,
+            });
+            if let Some(bound) = bound {
+                return Some(format!("{answer} cannot be right, {} was {}", bound.answer, bound.verdict));
+            }
+        }
+        let retry_at = attempts.iter()
+            .filter_map(|a| match a.verdict {
+                Verdict::Wait { seconds } => Some(a.submitted_at + seconds),
+                _ => None,
+            })
+            .max()?;
+        (retry_at > now).then(|| format!("the server asked to wait, try again in {}s", retry_at - now))
+    }
+
+    /// Appends `attempt` to the history file.
+    pub fn record(&mut self, attempt: Attempt) -> anyhow::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)
+            .with_context(|| format!("failed to open {}", self.path.display()))?;
+        writeln!(file, "{}", serde_json::to_string(&attempt)?)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+/// Submits `answer` unless the history already rules it out, recording the verdict.
+pub fn submit(
+    history: &mut History,
+    day: u8,
+    part: Part,
+    answer: Answer,
+    now: u64,
+    post: impl FnOnce(&Answer) -> anyhow::Result<String>,
+) -> anyhow::Result<Verdict> {
+    if let Some(reason) = history.refusal(day, part, &answer, now) {
+        anyhow::bail!("not submitting: {reason}");
+    }
+    let verdict = Verdict::parse(&post(&answer)?);
+    history.record(Attempt { day, part: part.number(), answer, submitted_at: now, verdict: verdict.clone() })?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::Client;
+    use crate::stub;
+
+    use super::*;
+
+    fn history(attempts: Vec<Attempt>) -> History {
+        History { path: PathBuf::new(), attempts }
+    }
+
+    fn attempt(answer: u64, verdict: Verdict) -> Attempt {
+        Attempt { day: 1, part: 1, answer: Answer::Unsigned(answer), submitted_at: 1000, verdict }
+    }
+
+    #[test]
+    fn parse_verdict_test() {
+        assert_eq!(Verdict::parse("<article><p>That's the right answer! You are one gold star closer.</p></article>"), Verdict::Correct);
+        assert_eq!(Verdict::parse("<p>That's not the right answer; your answer is too high.</p>"), Verdict::TooHigh);
+        assert_eq!(Verdict::parse("<p>That's not the right answer; your answer is too low.</p>"), Verdict::TooLow);
+        assert_eq!(Verdict::parse("<p>That's not the right answer. If you're stuck...</p>"), Verdict::Wrong);
+        assert_eq!(Verdict::parse("<p>You gave an answer too recently. You have 1m 5s left to wait.</p>"), Verdict::Wait { seconds: 65 });
+        assert_eq!(Verdict::parse("<p>You have 34s left to wait.</p>"), Verdict::Wait { seconds: 34 });
+        assert_eq!(Verdict::parse("<p>You don't seem to be solving the right level.  Did you already complete it?</p>"), Verdict::AlreadySolved);
+        assert_eq!(Verdict::parse("<main><article>Something new</article></main>"), Verdict::Unknown { response: "Something new".to_owned() });
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers_test() {
+        // given
+        let history = history(vec![
+            attempt(100, Verdict::TooHigh),
+            attempt(10, Verdict::TooLow),
+            attempt(42, Verdict::Wrong),
+        ]);
+        let refusal = |answer: u64| history.refusal(1, Part::One, &Answer::Unsigned(answer), 2000);
+
+        // then
+        assert!(refusal(42).is_some());
+        assert!(refusal(100).is_some() && refusal(150).is_some());
+        assert!(refusal(10).is_some() && refusal(3).is_some());
+        assert_eq!(refusal(50), None);
+        assert_eq!(history.refusal(1, Part::Two, &Answer::Unsigned(42), 2000), None);
+    }
+
+    #[test]
+    fn refuses_while_throttled_and_after_solving_test() {
+        // given
+        let throttled = history(vec![attempt(7, Verdict::Wait { seconds: 60 })]);
+        let solved = history(vec![attempt(7, Verdict::Correct)]);
+
+        // then
+        assert_eq!(throttled.refusal(1, Part::One, &Answer::Unsigned(8), 1030), Some("the server asked to wait, try again in 30s".to_owned()));
+        assert_eq!(throttled.refusal(1, Part::One, &Answer::Unsigned(8), 1060), None);
+        assert!(solved.refusal(1, Part::One, &Answer::Unsigned(8), 5000).is_some());
+    }
+
+    #[test]
+    fn unreadable_history_is_reported_test() {
+        // given
+        let dir = tempfile::tempdir().unwrap();
+
+        // when
+        let err = History::load(dir.path()).unwrap_err();
+
+        // then
+        assert_eq!(err.to_string(), format!("failed to read {}", dir.path().display()));
+    }
+
+    #[test]
+    fn submit_records_attempts_test() {
+        // given
+        let (config, requests) = stub::serve(vec![
+            (200, "<article><p>That's not the right answer; your answer is too low.</p></article>"),
+            (200, "<article><p>That's the right answer!</p></article>"),
+        ]);
+        let client = Client::new(&config).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("submissions.jsonl");
+        let mut history = History::load(&path).unwrap();
+        let post = |answer: &Answer| client.submit(3, Part::Two, answer);
+
+        // when
+        let first = submit(&mut history, 3, Part::Two, Answer::Unsigned(40), 1000, post).unwrap();
+        let refused = submit(&mut history, 3, Part::Two, Answer::Unsigned(39), 1100, post);
+        let second = submit(&mut history, 3, Part::Two, Answer::Unsigned(48), 1200, post).unwrap();
+
+        // then
+        assert_eq!((first, second), (Verdict::TooLow, Verdict::Correct));
+        assert!(refused.unwrap_err().to_string().contains("cannot be right"));
+        let request = requests.recv().unwrap();
+        assert_eq!((request.method.as_str(), request.url.as_str()), ("POST", "/2024/day/3/answer"));
+        assert_eq!(request.body, "level=2&answer=40");
+        assert_eq!(request.cookie.as_deref(), Some("session=s3cret"));
+        assert_eq!(requests.recv().unwrap().body, "level=2&answer=48");
+        let reloaded = History::load(&path).unwrap();
+        assert_eq!(reloaded.attempts.len(), 2);
+        assert_eq!(reloaded.attempts[1], Attempt { day: 3, part: 2, answer: Answer::Unsigned(48), submitted_at: 1200, verdict: Verdict::Correct });
+    }
+}
@@ -0,0 +1,112 @@
+use aoc_common::{Answer, Part, Registration, Run};
+
+use crate::answers::Answers;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail { expected: Answer },
+    /// No answer has been recorded for this part yet.
+    Missing,
+    /// The input could not be loaded or the solution returned an error.
+    Error(String),
+}
+
+/// Result of running one part against its real input and comparing it with the recorded answer.
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub run: Option<Run>,
+    pub status: Status,
+}
+
+pub fn verify(days: &[Registration], answers: &Answers) -> Vec<Check> {
+    days.iter()
+        .flat_map(|day| {
+            let input = day.load_input(None);
+            Part::ALL.map(|part| check(day, part, &input, answers))
+        })
+        .collect()
+}
+
+fn check(day: &Registration, part: Part, input: &anyhow::Result<String>, answers: &Answers) -> Check {
+    let result = match input {
+        Ok(input) => day.run(input, part),
+        Err(err) => Err(anyhow::anyhow!("{err:#}")),
+    };
+    let (run, status) = match result {
+        Err(err) => (None, Status::Error(format!("{err:#}"))),
+        Ok(run) => {
+            let status = match answers.get(day.day, part) {
+                None => Status::Missing,
+                Some(expected) if expected == run.answer => Status::Pass,
+                Some(expected) => Status::Fail { expected },
+            };
+            (Some(run), status)
+        }
+    };
+    Check { day: day.day, part, run, status }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{register, Solution};
+
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+        type Input = Vec<u64>;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.split_whitespace().map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+            Ok(input.iter().sum::<u64>().into())
+        }
+
+        fn part2(_: &Self::Input) -> anyhow::Result<Answer> {
+            anyhow::bail!("not solved yet")
+        }
+    }
+
+    #[test]
+    fn check_test() {
+        // given
+        let day = register::<Sum>();
+        let input = Ok("1 2 3".to_owned());
+        let matching: Answers = "[day1]\npart1 = \"6\"".parse().unwrap();
+        let different: Answers = "[day1]\npart1 = \"7\"".parse().unwrap();
+
+        // when
+        let pass = check(&day, Part::One, &input, &matching);
+        let fail = check(&day, Part::One, &input, &different);
+        let missing = check(&day, Part::One, &input, &Answers::default());
+        let error = check(&day, Part::Two, &input, &matching);
+
+        // then
+        assert_eq!(pass.status, Status::Pass);
+        assert_eq!(fail.status, Status::Fail { expected: Answer::Unsigned(7) });
+        assert_eq!(missing.status, Status::Missing);
+        assert_eq!(error.status, Status::Error("not solved yet".to_owned()));
+    }
+
+    #[test]
+    fn check_unreadable_input_test() {
+        // given
+        let day = register::<Sum>();
+        let input = Err(anyhow::anyhow!("no readable input for day 1"));
+
+        // when
+        let check = check(&day, Part::One, &input, &Answers::default());
+
+        // then
+        assert_eq!(check.status, Status::Error("no readable input for day 1".to_owned()));
+        assert!(check.run.is_none());
+    }
+}
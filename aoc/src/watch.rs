@@ -0,0 +1,130 @@
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use aoc_common::Registration;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+use crate::report::{Record, Status};
+
+/// Rebuilds and re-runs `day` whenever its `src/` or `resources/` change, until interrupted.
+pub fn watch(day: &Registration, root: &Path) -> anyhow::Result<()> {
+    let (sender, events) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    for dir in ["src", "resources"] {
+        watcher.watch(&Path::new(day.manifest_dir).join(dir), RecursiveMode::Recursive)?;
+    }
+
+    let mut previous = vec![];
+    loop {
+        if let Some(records) = run_once(day.day, root, &previous)? {
+            previous = records;
+        }
+        println!("Watching day {} for changes, Ctrl-C to stop", day.day);
+        wait_for_change(&events)?;
+    }
+}
+
+fn wait_for_change(events: &Receiver<notify::Result<Event>>) -> anyhow::Result<()> {
+    loop {
+        let event = events.recv()??;
+        if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)) {
+            break;
+        }
+    }
+    // a single save usually produces a burst of events
+    while events.recv_timeout(Duration::from_millis(200)).is_ok() {}
+    Ok(())
+}
+
+/// Builds the runner, runs the day's example tests and its real input, and prints the answers
+/// compared with `previous`. Returns `None` when the build failed.
+fn run_once(day: u8, root: &Path, previous: &[Record]) -> anyhow::Result<Option<Vec<Record>>> {
+    println!("== Day {day}");
+    if !cargo(root, &["build", "--quiet", "-p", "aoc"]).status()?.success() {
+        println!("Build failed");
+        return Ok(None);
+    }
+
+    let examples = cargo(root, &["test", "--quiet", "-p", &format!("day{day}"), "--lib", "examples::"])
+        .stdout(Stdio::null())
+        .status()?;
+    println!("Examples: {}", if examples.success() { "pass" } else { "FAIL" });
+
+    let output = cargo(root, &["run", "--quiet", "-p", "aoc", "--", "run", "--day", &day.to_string(), "--format", "json"])
+        .stderr(Stdio::null())
+        .output()?;
+    let records: Vec<Record> = serde_json::from_slice(&output.stdout)?;
+    for line in diff(previous, &records) {
+        println!("{line}");
+    }
+    Ok(Some(records))
+}
+
+fn cargo(root: &Path, args: &[&str]) -> Command {
+    let mut command = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(root).args(args);
+    command
+}
+
+/// One line per part of `current`, showing how its answer changed since `previous`.
+pub fn diff(previous: &[Record], current: &[Record]) -> Vec<String> {
+    current.iter()
+        .map(|record| {
+            let before = previous.iter()
+                .find(|r| r.part == record.part)
+                .and_then(|r| r.answer.as_ref());
+            let change = match (&record.answer, before) {
+                (None, Some(before)) => format!("ERROR {} (was {before})", record.error.as_deref().unwrap_or_default()),
+                (None, None) => format!("ERROR {}", record.error.as_deref().unwrap_or_default()),
+                (Some(answer), None) => format!("{answer}"),
+                (Some(answer), Some(before)) if answer == before => format!("{answer} (unchanged)"),
+                (Some(answer), Some(before)) => format!("{before} -> {answer}"),
+            };
+            let time = match (record.status, record.solve_time_ns) {
+                (Status::Error, _) | (_, None) => String::new(),
+                (_, Some(ns)) => format!(" in {:?}", Duration::from_nanos(ns)),
+            };
+            format!("Part {}: {change}{time}", record.part)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Answer, Part, Run};
+
+    use super::*;
+
+    fn record(part: Part, answer: anyhow::Result<u64>) -> Record {
+        let run = answer.map(|a| Run { answer: Answer::Unsigned(a), parse_time: Duration::ZERO, solve_time: Duration::from_millis(2) });
+        Record::solved(6, part, run)
+    }
+
+    #[test]
+    fn diff_test() {
+        // given
+        let previous = vec![record(Part::One, Ok(41)), record(Part::Two, Ok(6))];
+        let current = vec![record(Part::One, Ok(41)), record(Part::Two, Ok(7))];
+
+        // when
+        let lines = diff(&previous, &current);
+
+        // then
+        assert_eq!(lines, vec!["Part 1: 41 (unchanged) in 2ms", "Part 2: 6 -> 7 in 2ms"]);
+    }
+
+    #[test]
+    fn diff_first_run_and_errors_test() {
+        // given
+        let previous = vec![record(Part::Two, Ok(6))];
+        let current = vec![record(Part::One, Ok(41)), record(Part::Two, Err(anyhow::anyhow!("no guard")))];
+
+        // when
+        let lines = diff(&previous, &current);
+
+        // then
+        assert_eq!(lines, vec!["Part 1: 41 in 2ms", "Part 2: ERROR no guard (was 6)"]);
+    }
+}
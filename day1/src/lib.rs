@@ -0,0 +1,1011 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use aoc_common::parse::{lines, parse_all, IResult};
+use aoc_common::{Answer, Options, Part, Solution};
+use itertools::Itertools;
+use nom::character::complete::{char, digit1};
+use nom::combinator::{map_res, opt};
+use nom::error::context;
+use nom::multi::many1;
+use nom::sequence::{pair, preceded};
+use rand::rngs::StdRng;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    type Input = Vec<(u64, u64)>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        part2(input)
+    }
+
+    fn generate(size: usize, rng: &mut StdRng) -> anyhow::Result<String> {
+        Ok(generate::input(size, rng))
+    }
+
+    /// `lengths` picks the [`LengthPolicy`], `distance` and `similarity` the [`metric::Distance`]
+    /// and [`metric::Similarity`] of parts 1 and 2.
+    const OPTIONS: &'static [&'static str] = &["lengths", "distance", "similarity"];
+
+    fn parse_with_options(input: &str, options: &Options) -> anyhow::Result<Self::Input> {
+        parse_with(input, options.get_or_default("lengths")?)
+    }
+
+    fn solve_with_options(input: &Self::Input, part: Part, options: &Options) -> anyhow::Result<Answer> {
+        match part {
+            Part::One => part1_with(input, options.get_or_default::<metric::Distance>("distance")?.metric()),
+            Part::Two => part2_with(input, options.get_or_default::<metric::Similarity>("similarity")?.metric()),
+        }
+    }
+}
+
+aoc_common::example_tests!(crate::Day1);
+
+/// Parses the location id pairs, one per line, failing with [`MismatchedLengths`] when a line
+/// holds a single id.
+pub fn parse(input: &str) -> anyhow::Result<Vec<(u64, u64)>> {
+    parse_with(input, LengthPolicy::Error)
+}
+
+/// Parses the location id pairs, completing lines that hold a single id as `policy` says.
+pub fn parse_with(input: &str, policy: LengthPolicy) -> anyhow::Result<Vec<(u64, u64)>> {
+    let rows = parse_all(input, parse_input)?;
+    let short_lines = rows.iter().enumerate().filter(|(_, (_, right))| right.is_none()).map(|(index, _)| index + 1).collect();
+    policy.check(rows.len(), short_lines)?;
+    Ok(rows.into_iter().filter_map(|row| policy.complete(row)).collect())
+}
+
+/// Total distance between the left and right lists, pairing their ids smallest first.
+pub fn part1(pairs: &[(u64, u64)]) -> anyhow::Result<Answer> {
+    part1_with(pairs, &metric::Absolute)
+}
+
+/// Similarity score: every left id times the number of times it appears in the right list.
+pub fn part2(pairs: &[(u64, u64)]) -> anyhow::Result<Answer> {
+    part2_with(pairs, &metric::FrequencyWeighted)
+}
+
+/// Part 1 measured with another [`DistanceMetric`](metric::DistanceMetric).
+pub fn part1_with(pairs: &[(u64, u64)], metric: &dyn metric::DistanceMetric) -> anyhow::Result<Answer> {
+    part1::solution(pairs, metric)
+}
+
+/// Part 2 measured with another [`SimilarityMetric`](metric::SimilarityMetric).
+pub fn part2_with(pairs: &[(u64, u64)], metric: &dyn metric::SimilarityMetric) -> anyhow::Result<Answer> {
+    part2::solution(pairs, metric)
+}
+
+/// What to do with lines holding only a left location id, which leave the right list short.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum LengthPolicy {
+    /// Fail with [`MismatchedLengths`] naming every short line.
+    #[default]
+    Error,
+    /// Drop the lone left ids, cutting the left list down to the length of the right one.
+    ///
+    /// The ids dropped are the ones on the short lines, wherever they sort, not the largest left
+    /// ids as zipping the sorted lists would. This is what lets [`stream::Tally`] apply the policy
+    /// one line at a time.
+    Truncate,
+    /// Pair the lone left ids with a right id of zero.
+    PadWithZero,
+}
+
+impl FromStr for LengthPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(LengthPolicy::Error),
+            "truncate" => Ok(LengthPolicy::Truncate),
+            "pad" => Ok(LengthPolicy::PadWithZero),
+            _ => anyhow::bail!("unknown length policy {s:?}, expected error, truncate or pad"),
+        }
+    }
+}
+
+impl LengthPolicy {
+    fn complete(self, (left, right): (u64, Option<u64>)) -> Option<(u64, u64)> {
+        match (right, self) {
+            (Some(right), _) => Some((left, right)),
+            (None, LengthPolicy::PadWithZero) => Some((left, 0)),
+            (None, _) => None,
+        }
+    }
+
+    /// Fails under [`LengthPolicy::Error`] when any of the `rows` lines was short.
+    fn check(self, rows: usize, short_lines: Vec<usize>) -> Result<(), MismatchedLengths> {
+        if self == LengthPolicy::Error && !short_lines.is_empty() {
+            return Err(MismatchedLengths { lefts: rows, rights: rows - short_lines.len(), short_lines });
+        }
+        Ok(())
+    }
+}
+
+/// The left list is longer than the right one, because some lines hold a single location id.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MismatchedLengths {
+    pub lefts: usize,
+    pub rights: usize,
+    /// 1-based numbers of the lines missing their right id.
+    pub short_lines: Vec<usize>,
+}
+
+impl Display for MismatchedLengths {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the left list has {} ids but the right list has {}, short lines: {}", self.lefts, self.rights, self.short_lines.iter().join(", "))
+    }
+}
+
+impl std::error::Error for MismatchedLengths {}
+
+mod part1 {
+    use aoc_common::Answer;
+    use itertools::Itertools;
+
+    use crate::metric::DistanceMetric;
+
+    pub fn solution(pairs: &[(u64, u64)], metric: &dyn DistanceMetric) -> anyhow::Result<Answer> {
+        let (lefts, rights): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+        Ok(distance(&lefts, &rights, metric)?.into())
+    }
+
+    pub fn distance(lefts: &[u64], rights: &[u64], metric: &dyn DistanceMetric) -> anyhow::Result<u64> {
+        metric.distance(&mut lefts.iter().copied().sorted().zip(rights.iter().copied().sorted()))
+    }
+}
+
+mod part2 {
+    use std::collections::HashMap;
+    use std::hash::Hash;
+
+    use aoc_common::Answer;
+
+    use crate::metric::SimilarityMetric;
+
+    pub fn solution(pairs: &[(u64, u64)], metric: &dyn SimilarityMetric) -> anyhow::Result<Answer> {
+        let (lefts, rights): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+        Ok(similarity(&lefts, &rights, metric)?.into())
+    }
+
+    pub fn similarity(lefts: &[u64], rights: &[u64], metric: &dyn SimilarityMetric) -> anyhow::Result<u64> {
+        metric.similarity(&group_with_count(lefts.iter().copied()), &group_with_count(rights.iter().copied()))
+    }
+
+    fn group_with_count<I: Eq + Hash>(iterator: impl IntoIterator<Item=I>) -> HashMap<I, u64> {
+        let mut collector: HashMap<I, u64> = HashMap::new();
+        for item in iterator {
+            let freq: &mut u64 = collector.entry(item).or_insert(0);
+            *freq += 1;
+        }
+        collector
+    }
+}
+
+pub mod metric {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    use anyhow::Context;
+
+    /// How far apart two lists are, given their ids paired smallest first.
+    pub trait DistanceMetric {
+        fn distance(&self, pairs: &mut dyn Iterator<Item=(u64, u64)>) -> anyhow::Result<u64>;
+    }
+
+    /// How alike two lists are, given how often every id occurs in each of them.
+    pub trait SimilarityMetric {
+        fn similarity(&self, lefts: &HashMap<u64, u64>, rights: &HashMap<u64, u64>) -> anyhow::Result<u64>;
+    }
+
+    /// The built-in [`DistanceMetric`]s, named as the `distance` option selects them.
+    #[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+    pub enum Distance {
+        #[default]
+        Absolute,
+        Squared,
+        Relative,
+    }
+
+    impl Distance {
+        pub fn metric(self) -> &'static dyn DistanceMetric {
+            match self {
+                Distance::Absolute => &Absolute,
+                Distance::Squared => &Squared,
+                Distance::Relative => &RelativePercentage,
+            }
+        }
+    }
+
+    impl FromStr for Distance {
+        type Err = anyhow::Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "absolute" => Ok(Distance::Absolute),
+                "squared" => Ok(Distance::Squared),
+                "relative" => Ok(Distance::Relative),
+                _ => anyhow::bail!("unknown distance {s:?}, expected absolute, squared or relative"),
+            }
+        }
+    }
+
+    /// The built-in [`SimilarityMetric`]s, named as the `similarity` option selects them.
+    #[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+    pub enum Similarity {
+        #[default]
+        Frequency,
+        Jaccard,
+    }
+
+    impl Similarity {
+        pub fn metric(self) -> &'static dyn SimilarityMetric {
+            match self {
+                Similarity::Frequency => &FrequencyWeighted,
+                Similarity::Jaccard => &JaccardOverlap,
+            }
+        }
+    }
+
+    impl FromStr for Similarity {
+        type Err = anyhow::Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "frequency" => Ok(Similarity::Frequency),
+                "jaccard" => Ok(Similarity::Jaccard),
+                _ => anyhow::bail!("unknown similarity {s:?}, expected frequency or jaccard"),
+            }
+        }
+    }
+
+    /// Sum of the absolute differences of the pairs, the puzzle's own distance.
+    #[derive(Debug, Copy, Clone, Default)]
+    pub struct Absolute;
+
+    impl DistanceMetric for Absolute {
+        fn distance(&self, pairs: &mut dyn Iterator<Item=(u64, u64)>) -> anyhow::Result<u64> {
+            pairs.map(|(l, r)| l.abs_diff(r))
+                .try_fold(0u64, u64::checked_add)
+                .context("total distance overflows u64")
+        }
+    }
+
+    /// Sum of the squared differences of the pairs, so that a few far apart pairs outweigh many close ones.
+    #[derive(Debug, Copy, Clone, Default)]
+    pub struct Squared;
+
+    impl DistanceMetric for Squared {
+        fn distance(&self, pairs: &mut dyn Iterator<Item=(u64, u64)>) -> anyhow::Result<u64> {
+            pairs.map(|(l, r)| l.abs_diff(r)).try_fold(0u64, |sum, d| sum.checked_add(d.checked_mul(d)?))
+                .context("total squared distance overflows u64")
+        }
+    }
+
+    /// Total absolute difference as a percentage of the larger id of every pair, rounded down.
+    #[derive(Debug, Copy, Clone, Default)]
+    pub struct RelativePercentage;
+
+    impl DistanceMetric for RelativePercentage {
+        fn distance(&self, pairs: &mut dyn Iterator<Item=(u64, u64)>) -> anyhow::Result<u64> {
+            let (difference, total) = pairs.fold((0u128, 0u128), |(difference, total), (l, r)| {
+                (difference + u128::from(l.abs_diff(r)), total + u128::from(l.max(r)))
+            });
+            Ok((difference * 100).checked_div(total).map_or(0, |percentage| percentage as u64))
+        }
+    }
+
+    /// Sum of every left id times the number of times it appears in the right list, the puzzle's
+    /// own similarity score.
+    #[derive(Debug, Copy, Clone, Default)]
+    pub struct FrequencyWeighted;
+
+    impl SimilarityMetric for FrequencyWeighted {
+        fn similarity(&self, lefts: &HashMap<u64, u64>, rights: &HashMap<u64, u64>) -> anyhow::Result<u64> {
+            lefts.iter()
+                .try_fold(0u64, |sum, (&id, &count)| {
+                    let right_count = rights.get(&id).copied().unwrap_or(0);
+                    sum.checked_add(id.checked_mul(count)?.checked_mul(right_count)?)
+                })
+                .context("similarity score overflows u64")
+        }
+    }
+
+    /// Distinct ids found in both lists as a percentage of the distinct ids found in either, rounded down.
+    #[derive(Debug, Copy, Clone, Default)]
+    pub struct JaccardOverlap;
+
+    impl SimilarityMetric for JaccardOverlap {
+        fn similarity(&self, lefts: &HashMap<u64, u64>, rights: &HashMap<u64, u64>) -> anyhow::Result<u64> {
+            let both = lefts.keys().filter(|id| rights.contains_key(id)).count() as u64;
+            let either = (lefts.len() + rights.len()) as u64 - both;
+            Ok((both * 100).checked_div(either).unwrap_or(0))
+        }
+    }
+}
+
+pub mod columns {
+    use anyhow::bail;
+    use aoc_common::parse::{lines, parse_all, IResult};
+    use nom::character::complete::{digit1, space0, space1};
+    use nom::combinator::map_res;
+    use nom::error::context;
+    use nom::multi::separated_list1;
+    use nom::sequence::delimited;
+
+    use crate::metric::{Absolute, DistanceMetric, FrequencyWeighted, SimilarityMetric};
+    use crate::{part1, part2};
+
+    /// Parses rows of any number of whitespace separated location ids into one list per column.
+    pub fn parse(input: &str) -> anyhow::Result<Vec<Vec<u64>>> {
+        let rows = parse_all(input, lines(parse_row))?;
+        let width = rows[0].len();
+        if let Some((index, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            bail!("line {} has {} columns, expected {width}", index + 1, row.len());
+        }
+        Ok((0..width).map(|column| rows.iter().map(|row| row[column]).collect()).collect())
+    }
+
+    /// Total distance and similarity score of every pair of columns, both indexed `[i][j]`.
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    pub struct Matrix {
+        pub distance: Vec<Vec<u64>>,
+        pub similarity: Vec<Vec<u64>>,
+    }
+
+    /// Compares every column against every other one, as parts 1 and 2 compare the left and right lists.
+    pub fn matrix(columns: &[Vec<u64>]) -> anyhow::Result<Matrix> {
+        matrix_with(columns, &Absolute, &FrequencyWeighted)
+    }
+
+    /// [`matrix`] measured with other metrics.
+    pub fn matrix_with(columns: &[Vec<u64>], distance: &dyn DistanceMetric, similarity: &dyn SimilarityMetric) -> anyhow::Result<Matrix> {
+        Ok(Matrix {
+            distance: pairwise(columns, |i, j| part1::distance(i, j, distance))?,
+            similarity: pairwise(columns, |i, j| part2::similarity(i, j, similarity))?,
+        })
+    }
+
+    fn pairwise(columns: &[Vec<u64>], measure: impl Fn(&[u64], &[u64]) -> anyhow::Result<u64>) -> anyhow::Result<Vec<Vec<u64>>> {
+        columns.iter()
+            .map(|i| columns.iter().map(|j| measure(i, j)).collect())
+            .collect()
+    }
+
+    fn parse_row(input: &str) -> IResult<'_, Vec<u64>> {
+        context("location row", delimited(space0, separated_list1(space1, map_res(digit1, str::parse)), space0))(input)
+    }
+}
+
+pub mod explain {
+    use std::array;
+    use std::cmp::Reverse;
+    use std::fmt::{Display, Formatter};
+
+    use itertools::Itertools;
+    use serde::Serialize;
+
+    use crate::metric::{Absolute, FrequencyWeighted};
+    use crate::{part1, part2};
+
+    /// Both answers broken down into the pairs and ids they are summed from.
+    #[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+    pub struct Explanation {
+        pub distance: u64,
+        pub similarity: u64,
+        /// Both lists paired smallest first.
+        pub pairs: Vec<PairDistance>,
+        /// The pairs furthest apart, furthest first.
+        pub top_distances: Vec<PairDistance>,
+        /// The ids adding the most to the similarity score, most first.
+        pub top_similarities: Vec<Contribution>,
+    }
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize)]
+    pub struct PairDistance {
+        pub left: u64,
+        pub right: u64,
+        pub distance: u64,
+    }
+
+    /// An id's share of the similarity score, itself times how often it occurs in each list.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize)]
+    pub struct Contribution {
+        pub value: u64,
+        pub left_count: u64,
+        pub right_count: u64,
+        pub contribution: u64,
+    }
+
+    /// Explains the puzzle's answers for `pairs`, keeping the `k` largest distances and similarity contributions.
+    pub fn explain(pairs: &[(u64, u64)], k: usize) -> anyhow::Result<Explanation> {
+        let (lefts, rights): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+        let distance = part1::distance(&lefts, &rights, &Absolute)?;
+        let similarity = part2::similarity(&lefts, &rights, &FrequencyWeighted)?;
+
+        let pairs = lefts.iter().copied().sorted().zip(rights.iter().copied().sorted())
+            .map(|(left, right)| PairDistance { left, right, distance: left.abs_diff(right) })
+            .collect_vec();
+        let top_distances = pairs.iter().copied().sorted_by_key(|pair| Reverse(pair.distance)).take(k).collect();
+
+        // Every contribution is a term of the similarity score, which did not overflow.
+        let right_counts = rights.iter().counts();
+        let top_similarities = lefts.iter().counts().into_iter()
+            .filter_map(|(&value, left_count)| {
+                let (left_count, right_count) = (left_count as u64, *right_counts.get(&value)? as u64);
+                Some(Contribution { value, left_count, right_count, contribution: value * left_count * right_count })
+            })
+            .filter(|contribution| contribution.contribution > 0)
+            .sorted_by_key(|contribution| (Reverse(contribution.contribution), contribution.value))
+            .take(k)
+            .collect();
+
+        Ok(Explanation { distance, similarity, pairs, top_distances, top_similarities })
+    }
+
+    impl Display for Explanation {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            writeln!(f, "Total distance: {}", self.distance)?;
+            writeln!(f, "Similarity score: {}", self.similarity)?;
+            let distances = |pairs: &[PairDistance]| pairs.iter().map(|pair| [pair.left, pair.right, pair.distance]).collect_vec();
+            writeln!(f)?;
+            write_table(f, "Sorted pairing", ["left", "right", "distance"], &distances(&self.pairs))?;
+            writeln!(f)?;
+            write_table(f, "Largest distances", ["left", "right", "distance"], &distances(&self.top_distances))?;
+            writeln!(f)?;
+            let contributions = self.top_similarities.iter().map(|c| [c.value, c.left_count, c.right_count, c.contribution]).collect_vec();
+            write_table(f, "Largest similarity contributions", ["value", "left count", "right count", "contribution"], &contributions)
+        }
+    }
+
+    /// Writes `rows` under `title` and `headers`, every column right aligned.
+    fn write_table<const N: usize>(f: &mut Formatter<'_>, title: &str, headers: [&str; N], rows: &[[u64; N]]) -> std::fmt::Result {
+        let widths: [usize; N] = array::from_fn(|i| rows.iter().map(|row| row[i].to_string().len()).fold(headers[i].len(), usize::max));
+        let line = |cells: [String; N]| cells.iter().zip(widths).map(|(cell, width)| format!("{cell:>width$}")).join("  ");
+        writeln!(f, "{title}")?;
+        writeln!(f, "{}", line(headers.map(str::to_owned)))?;
+        for row in rows {
+            writeln!(f, "{}", line(row.map(|value| value.to_string())))?;
+        }
+        Ok(())
+    }
+}
+
+pub mod generate {
+    use rand::Rng;
+
+    /// `size` pairs of five digit location ids. About a third of the right ids repeat an earlier
+    /// left one, so that part 2 has similarities to find.
+    pub fn input(size: usize, rng: &mut impl Rng) -> String {
+        let mut lefts = Vec::with_capacity(size);
+        let mut input = String::new();
+        for _ in 0..size {
+            let left = rng.gen_range(10000..100000u64);
+            lefts.push(left);
+            let right = if rng.gen_bool(0.3) { lefts[rng.gen_range(0..lefts.len())] } else { rng.gen_range(10000..100000) };
+            input.push_str(&format!("{left}   {right}\n"));
+        }
+        input
+    }
+}
+
+pub mod stream {
+    use std::collections::HashMap;
+    use std::io::BufRead;
+    use std::iter;
+
+    use anyhow::Context;
+    use aoc_common::parse::parse_all;
+    use aoc_common::{DayArgs, Solution};
+    use itertools::Itertools;
+
+    use crate::metric::{self, Absolute, DistanceMetric, FrequencyWeighted, SimilarityMetric};
+    use crate::{parse_line, Day1, LengthPolicy};
+
+    /// Prints both answers like [`aoc_common::print_answers`], reading the input a line at a time
+    /// from wherever the loaded input would come from.
+    pub fn print_answers(args: DayArgs) -> anyhow::Result<()> {
+        args.options.check(Day1::DAY, Day1::OPTIONS)?;
+        let reader = args.locator::<Day1>().open(Day1::DAY)?;
+        let tally = Tally::read_with(reader, args.options.get_or_default("lengths")?)?;
+        let distance = tally.distance_with(args.options.get_or_default::<metric::Distance>("distance")?.metric())?;
+        let similarity = tally.similarity_with(args.options.get_or_default::<metric::Similarity>("similarity")?.metric())?;
+        println!("Day {} Part 1 answer: {distance}", Day1::DAY);
+        println!("Day {} Part 2 answer: {similarity}", Day1::DAY);
+        Ok(())
+    }
+
+    /// How often every location id occurs in each list, read one line at a time.
+    ///
+    /// Both answers only depend on these counts, so memory grows with the number of distinct ids
+    /// rather than with the number of lines.
+    #[derive(Debug, Clone, Default, Eq, PartialEq)]
+    pub struct Tally {
+        lefts: HashMap<u64, u64>,
+        rights: HashMap<u64, u64>,
+    }
+
+    impl Tally {
+        /// Reads every pair from `reader` in a single pass, failing with
+        /// [`MismatchedLengths`](crate::MismatchedLengths) when a line holds a single id.
+        pub fn read(reader: impl BufRead) -> anyhow::Result<Self> {
+            Self::read_with(reader, LengthPolicy::Error)
+        }
+
+        /// Reads every pair from `reader`, completing lines that hold a single id as `policy` says.
+        pub fn read_with(mut reader: impl BufRead, policy: LengthPolicy) -> anyhow::Result<Self> {
+            let mut tally = Self::default();
+            let mut short_lines = vec![];
+            let mut line = String::new();
+            let mut number = 1;
+            loop {
+                line.clear();
+                if reader.read_line(&mut line).with_context(|| format!("failed to read line {number}"))? == 0 {
+                    break;
+                }
+                let row = parse_all(line.trim_end_matches(['\n', '\r']), parse_line).map_err(|mut err| {
+                    err.line = number;
+                    err
+                })?;
+                if row.1.is_none() {
+                    short_lines.push(number);
+                }
+                if let Some(pair) = policy.complete(row) {
+                    tally.add(pair);
+                }
+                number += 1;
+            }
+            if number == 1 {
+                // an empty input fails to parse the first pair, as it does when loaded whole
+                parse_all("", parse_line)?;
+            }
+            policy.check(number - 1, short_lines)?;
+            Ok(tally)
+        }
+
+        pub fn add(&mut self, (left, right): (u64, u64)) {
+            *self.lefts.entry(left).or_default() += 1;
+            *self.rights.entry(right).or_default() += 1;
+        }
+
+        /// Total distance, pairing the ids of both lists smallest first.
+        pub fn distance(&self) -> anyhow::Result<u64> {
+            self.distance_with(&Absolute)
+        }
+
+        /// Similarity score, every left id times the number of times it appears in the right list.
+        pub fn similarity(&self) -> anyhow::Result<u64> {
+            self.similarity_with(&FrequencyWeighted)
+        }
+
+        pub fn distance_with(&self, metric: &dyn DistanceMetric) -> anyhow::Result<u64> {
+            metric.distance(&mut self.sorted_pairs())
+        }
+
+        pub fn similarity_with(&self, metric: &dyn SimilarityMetric) -> anyhow::Result<u64> {
+            metric.similarity(&self.lefts, &self.rights)
+        }
+
+        /// The pairs of both lists smallest first, expanded from the runs of equal ids.
+        fn sorted_pairs(&self) -> impl Iterator<Item=(u64, u64)> + '_ {
+            let sorted = |counts: &HashMap<u64, u64>| counts.iter().map(|(&id, &count)| (id, count)).sorted();
+            let (mut lefts, mut rights) = (sorted(&self.lefts), sorted(&self.rights));
+            let (mut left, mut right) = (lefts.next(), rights.next());
+            iter::from_fn(move || {
+                let ((l, l_count), (r, r_count)) = (left?, right?);
+                let paired = l_count.min(r_count);
+                left = if l_count == paired { lefts.next() } else { Some((l, l_count - paired)) };
+                right = if r_count == paired { rights.next() } else { Some((r, r_count - paired)) };
+                Some(iter::repeat_n((l, r), paired as usize))
+            }).flatten()
+        }
+    }
+}
+
+fn parse_input(input: &str) -> IResult<'_, Vec<(u64, Option<u64>)>> {
+    lines(parse_line)(input)
+}
+
+/// A left location id, followed by the right one unless the line is short.
+fn parse_line(input: &str) -> IResult<'_, (u64, Option<u64>)> {
+    context("location pair", pair(map_res(digit1, str::parse), opt(preceded(many1(char(' ')), map_res(digit1, str::parse)))))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::iter;
+
+    use aoc_common::parse::ParseError;
+    use proptest::prelude::*;
+    use rand::SeedableRng;
+
+    use super::metric::{DistanceMetric, SimilarityMetric};
+    use super::*;
+
+    #[test]
+    fn parse_line_test() {
+        // given
+        let line = "1   4\n";
+
+        // when
+        let (_, result) = parse_line(line).unwrap();
+
+        // then
+        assert_eq!(result, (1, Some(4)));
+    }
+
+    #[test]
+    fn parse_test() {
+        // given
+        let line = "3   4
+4   3
+2   5";
+
+        // when
+        let (_, result) = parse_input(line).unwrap();
+
+        // then
+        assert_eq!(result, vec![(3, Some(4)), (4, Some(3)), (2, Some(5))]);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_line_test() {
+        // given
+        let input = "3   4
+4   3
+2   x
+1   3
+";
+
+        // when
+        let err = Day1::parse(input).unwrap_err();
+
+        // then
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column), (3, 2));
+        assert_eq!(err.to_string(), "3:2: expected end of line
+3 | 2   x
+  |  ^");
+    }
+
+    #[test]
+    fn parse_rejects_short_lines_test() {
+        // given
+        let input = "3   4
+4   3
+2
+1   3
+5
+";
+
+        // when
+        let err = Day1::parse(input).unwrap_err();
+
+        // then
+        let err = err.downcast::<MismatchedLengths>().unwrap();
+        assert_eq!(err, MismatchedLengths { lefts: 5, rights: 3, short_lines: vec![3, 5] });
+        assert_eq!(err.to_string(), "the left list has 5 ids but the right list has 3, short lines: 3, 5");
+    }
+
+    #[test]
+    fn parse_with_policy_test() {
+        // given
+        let input = "3   4\n2\n1   3\n";
+
+        // when
+        let truncated = parse_with(input, LengthPolicy::Truncate).unwrap();
+        let padded = parse_with(input, LengthPolicy::PadWithZero).unwrap();
+
+        // then
+        assert_eq!(truncated, vec![(3, 4), (1, 3)]);
+        assert_eq!(padded, vec![(3, 4), (2, 0), (1, 3)]);
+    }
+
+    #[test]
+    fn truncate_drops_the_short_lines_test() {
+        // given
+        let input = "50\n1   10\n100   20\n";
+
+        // when
+        let pairs = parse_with(input, LengthPolicy::Truncate).unwrap();
+
+        // then
+        // zipping the sorted lists would pair 1 and 50 with 10 and 20, for a distance of 39
+        assert_eq!(part1(&pairs).unwrap(), Answer::from(89u64));
+    }
+
+    #[test]
+    fn parse_crlf_test() {
+        // given
+        let input = "3   4\r\n4   3\r\n";
+
+        // when
+        let result = Day1::parse(input).unwrap();
+
+        // then
+        assert_eq!(result, vec![(3, 4), (4, 3)]);
+    }
+
+    /// Pairs the smallest remaining numbers of both lists, one pair at a time.
+    fn distance_oracle(pairs: &[(u64, u64)]) -> u64 {
+        let (mut lefts, mut rights): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+        let mut sum = 0;
+        while !lefts.is_empty() {
+            let l = lefts.remove(lefts.iter().enumerate().min_by_key(|(_, &l)| l).unwrap().0);
+            let r = rights.remove(rights.iter().enumerate().min_by_key(|(_, &r)| r).unwrap().0);
+            sum += l.abs_diff(r);
+        }
+        sum
+    }
+
+    fn similarity_oracle(pairs: &[(u64, u64)]) -> u64 {
+        pairs.iter()
+            .map(|&(l, _)| l * pairs.iter().filter(|&&(_, r)| r == l).count() as u64)
+            .sum()
+    }
+
+    proptest! {
+        #[test]
+        fn solution_matches_oracle_test(pairs in prop::collection::vec((0..20u64, 0..20u64), 1..40)) {
+            // given
+            let input: String = pairs.iter().map(|(l, r)| format!("{l}   {r}\n")).collect();
+
+            // when
+            let parsed = Day1::parse(&input).unwrap();
+
+            // then
+            prop_assert_eq!(Day1::part1(&parsed).unwrap(), Answer::from(distance_oracle(&pairs)));
+            prop_assert_eq!(Day1::part2(&parsed).unwrap(), Answer::from(similarity_oracle(&pairs)));
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+        #[test]
+        fn generated_input_has_size_pairs_test(size in 1..200usize, seed in any::<u64>()) {
+            // when
+            let pairs = parse(&generate::input(size, &mut StdRng::seed_from_u64(seed))).unwrap();
+
+            // then
+            prop_assert_eq!(pairs.len(), size);
+            prop_assert!(pairs.iter().all(|&(left, right)| (10000..100000).contains(&left) && (10000..100000).contains(&right)));
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+        #[test]
+        fn stream_matches_solution_test(size in 1..500usize, seed in any::<u64>()) {
+            // given
+            let input = Day1::generate(size, &mut StdRng::seed_from_u64(seed)).unwrap();
+            let pairs = parse(&input).unwrap();
+
+            // when
+            let tally = stream::Tally::read(input.as_bytes()).unwrap();
+
+            // then
+            prop_assert_eq!(Answer::from(tally.distance().unwrap()), part1(&pairs).unwrap());
+            prop_assert_eq!(Answer::from(tally.similarity().unwrap()), part2(&pairs).unwrap());
+        }
+    }
+
+    #[test]
+    fn columns_parse_test() {
+        // given
+        let input = "3   4\t1
+4 3   9
+  2   5   2  
+";
+
+        // when
+        let result = columns::parse(input).unwrap();
+
+        // then
+        assert_eq!(result, vec![vec![3, 4, 2], vec![4, 3, 5], vec![1, 9, 2]]);
+    }
+
+    #[test]
+    fn columns_parse_rejects_ragged_rows_test() {
+        // given
+        let input = "3   4   1\n4   3\n";
+
+        // when
+        let err = columns::parse(input).unwrap_err();
+
+        // then
+        assert_eq!(err.to_string(), "line 2 has 2 columns, expected 3");
+    }
+
+    #[test]
+    fn columns_matrix_test() {
+        // given
+        let columns = vec![vec![3, 4, 2, 1, 3, 3], vec![4, 3, 5, 3, 9, 3], vec![3, 3, 3, 3, 3, 3]];
+
+        // when
+        let result = columns::matrix(&columns).unwrap();
+
+        // then
+        assert_eq!(result.distance, vec![vec![0, 11, 4], vec![11, 0, 9], vec![4, 9, 0]]);
+        assert_eq!(result.similarity, vec![vec![34, 31, 54], vec![31, 45, 54], vec![54, 54, 108]]);
+    }
+
+    proptest! {
+        #[test]
+        fn columns_matrix_matches_solution_test(pairs in prop::collection::vec((0..20u64, 0..20u64), 1..40)) {
+            // given
+            let input: String = pairs.iter().map(|(l, r)| format!("{l}   {r}\n")).collect();
+
+            // when
+            let matrix = columns::matrix(&columns::parse(&input).unwrap()).unwrap();
+
+            // then
+            prop_assert_eq!(Answer::from(matrix.distance[0][1]), part1(&pairs).unwrap());
+            prop_assert_eq!(Answer::from(matrix.similarity[0][1]), part2(&pairs).unwrap());
+        }
+    }
+
+    #[test]
+    fn metrics_test() {
+        // given
+        let pairs = parse(include_str!("../resources/examples/example.txt")).unwrap();
+
+        // when
+        let squared = part1_with(&pairs, &metric::Squared).unwrap();
+        let relative = part1_with(&pairs, &metric::RelativePercentage).unwrap();
+        let jaccard = part2_with(&pairs, &metric::JaccardOverlap).unwrap();
+
+        // then
+        assert_eq!(squared, Answer::from(35u64));
+        assert_eq!(relative, Answer::from(40u64));
+        assert_eq!(jaccard, Answer::from(33u64));
+    }
+
+    #[test]
+    fn options_select_metrics_and_length_policy_test() {
+        // given
+        let registration = aoc_common::register::<Day1>();
+        let input = "3   4\n4   3\n2\n1   3\n3   9\n3   3\n";
+        let options = Options::parse(["distance=squared", "similarity=jaccard", "lengths=pad"]).unwrap();
+
+        // when
+        let part1 = registration.run_with(input, Part::One, &options).unwrap();
+        let part2 = registration.run_with(input, Part::Two, &options).unwrap();
+        let unknown = registration.run_with(input, Part::One, &Options::parse(["lengths=pad", "distance=manhattan"]).unwrap());
+
+        // then
+        let pairs = parse_with(input, LengthPolicy::PadWithZero).unwrap();
+        assert_eq!(part1.answer, part1_with(&pairs, &metric::Squared).unwrap());
+        assert_eq!(part2.answer, part2_with(&pairs, &metric::JaccardOverlap).unwrap());
+        assert_eq!(format!("{:#}", unknown.unwrap_err()), "invalid value for option distance: unknown distance \"manhattan\", expected absolute, squared or relative");
+    }
+
+    #[test]
+    fn metrics_of_empty_lists_test() {
+        // given
+        let empty = HashMap::new();
+
+        // when
+        let relative = metric::RelativePercentage.distance(&mut iter::empty()).unwrap();
+        let jaccard = metric::JaccardOverlap.similarity(&empty, &empty).unwrap();
+
+        // then
+        assert_eq!((relative, jaccard), (0, 0));
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+        #[test]
+        fn stream_metrics_match_solution_test(size in 1..200usize, seed in any::<u64>()) {
+            // given
+            let input = Day1::generate(size, &mut StdRng::seed_from_u64(seed)).unwrap();
+            let pairs = parse(&input).unwrap();
+
+            // when
+            let tally = stream::Tally::read(input.as_bytes()).unwrap();
+
+            // then
+            prop_assert_eq!(Answer::from(tally.distance_with(&metric::Squared).unwrap()), part1_with(&pairs, &metric::Squared).unwrap());
+            prop_assert_eq!(Answer::from(tally.distance_with(&metric::RelativePercentage).unwrap()), part1_with(&pairs, &metric::RelativePercentage).unwrap());
+            prop_assert_eq!(Answer::from(tally.similarity_with(&metric::JaccardOverlap).unwrap()), part2_with(&pairs, &metric::JaccardOverlap).unwrap());
+        }
+    }
+
+    #[test]
+    fn explain_test() {
+        // given
+        let pairs = parse(include_str!("../resources/examples/example.txt")).unwrap();
+
+        // when
+        let explanation = explain::explain(&pairs, 2).unwrap();
+
+        // then
+        let pair = |left, right, distance| explain::PairDistance { left, right, distance };
+        assert_eq!((explanation.distance, explanation.similarity), (11, 31));
+        assert_eq!(explanation.pairs, vec![pair(1, 3, 2), pair(2, 3, 1), pair(3, 3, 0), pair(3, 4, 1), pair(3, 5, 2), pair(4, 9, 5)]);
+        assert_eq!(explanation.top_distances, vec![pair(4, 9, 5), pair(1, 3, 2)]);
+        assert_eq!(explanation.top_similarities, vec![
+            explain::Contribution { value: 3, left_count: 3, right_count: 3, contribution: 27 },
+            explain::Contribution { value: 4, left_count: 1, right_count: 1, contribution: 4 },
+        ]);
+    }
+
+    #[test]
+    fn explain_table_test() {
+        // given
+        let pairs = vec![(3, 4), (10, 3), (3, 3)];
+
+        // when
+        let table = explain::explain(&pairs, 1).unwrap().to_string();
+
+        // then
+        assert_eq!(table, "Total distance: 6
+Similarity score: 12
+
+Sorted pairing
+left  right  distance
+   3      3         0
+   3      3         0
+  10      4         6
+
+Largest distances
+left  right  distance
+  10      4         6
+
+Largest similarity contributions
+value  left count  right count  contribution
+    3           2            2            12
+");
+    }
+
+    #[test]
+    fn stream_rejects_malformed_line_test() {
+        // given
+        let input = "3   4\r\n4   3\n2 x\n1   3\n";
+
+        // when
+        let err = stream::Tally::read(input.as_bytes()).unwrap_err();
+
+        // then
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column), (3, 2));
+    }
+
+    #[test]
+    fn stream_rejects_empty_input_test() {
+        // when
+        let err = stream::Tally::read("".as_bytes()).unwrap_err();
+
+        // then
+        assert_eq!(err.downcast::<ParseError>().unwrap(), parse("").unwrap_err().downcast::<ParseError>().unwrap());
+    }
+
+    #[test]
+    fn stream_length_policy_matches_parse_test() {
+        // given
+        let input = "3   4\r\n2\n4   3\n1\n1   3\n";
+
+        for policy in [LengthPolicy::Truncate, LengthPolicy::PadWithZero] {
+            // when
+            let tally = stream::Tally::read_with(input.as_bytes(), policy).unwrap();
+
+            // then
+            let pairs = parse_with(input, policy).unwrap();
+            assert_eq!(Answer::from(tally.distance().unwrap()), part1(&pairs).unwrap());
+            assert_eq!(Answer::from(tally.similarity().unwrap()), part2(&pairs).unwrap());
+        }
+        let err = stream::Tally::read(input.as_bytes()).unwrap_err();
+        assert_eq!(err.downcast::<MismatchedLengths>().unwrap().short_lines, vec![2, 4]);
+    }
+}
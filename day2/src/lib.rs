@@ -0,0 +1,345 @@
+use aoc_common::parse::{lines, parse_all, IResult};
+use aoc_common::{Answer, Solution};
+use nom::character::complete::{char, digit1};
+use nom::combinator::map_res;
+use nom::error::context;
+use nom::multi::separated_list1;
+use rand::rngs::StdRng;
+
+pub type Level = i64;
+/// Levels of one report, in order.
+pub type Report = Vec<Level>;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    type Input = Vec<Report>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        part2(input)
+    }
+
+    fn generate(size: usize, rng: &mut StdRng) -> anyhow::Result<String> {
+        Ok(generate::input(size, rng))
+    }
+}
+
+aoc_common::example_tests!(crate::Day2);
+
+/// Parses the reports, one per line.
+pub fn parse(input: &str) -> anyhow::Result<Vec<Report>> {
+    Ok(parse_all(input, parse_input)?)
+}
+
+/// Number of safe reports.
+pub fn part1(reports: &[Report]) -> anyhow::Result<Answer> {
+    part1::solution(reports)
+}
+
+/// Number of reports that are safe once the Problem Dampener removed at most one level.
+pub fn part2(reports: &[Report]) -> anyhow::Result<Answer> {
+    part2::solution(reports)
+}
+
+mod part1 {
+    use aoc_common::Answer;
+    use crate::{Change, Level, Report};
+
+    pub fn solution(reports: &[Report]) -> anyhow::Result<Answer> {
+        let count_valid_reports = reports.iter().filter(|r| is_report_valid(r)).count();
+
+        Ok(count_valid_reports.into())
+    }
+
+    fn is_report_valid(report: impl AsRef<[Level]>) -> bool {
+        let mut level_change_type: Option<Change> = None;
+        let mut last_level: Option<Level> = None;
+
+        for level in report.as_ref() {
+            if let Some(last_level) = last_level {
+                let change: Change = (last_level - level).into();
+                if !change.has_valid_rate() {
+                    return false;
+                }
+                match level_change_type {
+                    None => level_change_type = Some(change),
+                    Some(ref last_change) => {
+                        if !change.has_same_direction(last_change) {
+                            return false;
+                        }
+                    }
+                }
+            }
+            last_level = Some(*level);
+        }
+        true
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use proptest::prelude::*;
+
+        use super::*;
+
+        #[test]
+        fn is_report_valid_tet() {
+            // given
+            let report = vec![7, 6, 4, 2, 1];
+            // when
+            let valid = is_report_valid(report);
+
+            // then
+            assert!(valid)
+        }
+
+        proptest! {
+            #[test]
+            fn is_report_valid_matches_oracle_test(report in crate::tests::report()) {
+                prop_assert_eq!(is_report_valid(&report), crate::tests::is_safe(&report));
+            }
+        }
+    }
+}
+
+mod part2 {
+    use aoc_common::Answer;
+    use crate::{Change, Level, Report};
+
+    pub fn solution(reports: &[Report]) -> anyhow::Result<Answer> {
+        let count_valid_reports = reports.iter()
+            .filter(|r| is_report_valid_with_dumper(r)).count();
+
+        Ok(count_valid_reports.into())
+    }
+
+    fn is_report_valid_with_dumper(report: impl AsRef<[Level]>) -> bool {
+        for (idx, _) in report.as_ref().iter().enumerate() {
+            let report = report.as_ref();
+            if is_report_valid([&report[..idx], &report[idx + 1..]].concat()) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn is_report_valid(report: impl AsRef<[Level]>) -> bool {
+        let mut level_change_type: Option<Change> = None;
+        let mut last_level: Option<Level> = None;
+
+        for level in report.as_ref() {
+            if let Some(last_level) = last_level {
+                let change: Change = (last_level - level).into();
+                if !change.has_valid_rate() {
+                    return false;
+                }
+                match level_change_type {
+                    None => level_change_type = Some(change),
+                    Some(ref last_change) => {
+                        if !change.has_same_direction(last_change) {
+                            return false;
+                        }
+                    }
+                }
+            }
+            last_level = Some(*level);
+        }
+        true
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use proptest::prelude::*;
+
+        use super::*;
+
+        #[test]
+        fn is_report_valid_tet() {
+            // given
+            let report = vec![7, 6, 4, 2, 1];
+            // when
+            let valid = is_report_valid(&report);
+
+            // then
+            assert!(valid)
+        }
+
+        /// Walks the report once per direction and only tries dropping one of the two levels
+        /// around the first bad step.
+        fn linear_dampener(report: &[Level]) -> bool {
+            [1, -1].into_iter().any(|sign| {
+                let ok = |a: Level, b: Level| (1..=3).contains(&((b - a) * sign));
+                let Some(bad) = report.windows(2).position(|w| !ok(w[0], w[1])) else { return true };
+                [bad, bad + 1].into_iter().any(|skip| {
+                    let rest: Vec<_> = report.iter().enumerate().filter(|&(i, _)| i != skip).map(|(_, &l)| l).collect();
+                    rest.windows(2).all(|w| ok(w[0], w[1]))
+                })
+            })
+        }
+
+        proptest! {
+            #[test]
+            fn is_report_valid_with_dumper_matches_linear_dampener_test(report in crate::tests::report()) {
+                prop_assert_eq!(is_report_valid_with_dumper(&report), linear_dampener(&report));
+            }
+        }
+    }
+}
+
+pub mod generate {
+    use rand::Rng;
+
+    use crate::Level;
+
+    /// `size` reports of five to eight levels, mostly changing by one to three in one direction,
+    /// with the occasional step that a dampener may or may not be able to remove.
+    pub fn input(size: usize, rng: &mut impl Rng) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            let direction = if rng.gen_bool(0.5) { 1 } else { -1 };
+            let mut levels: Vec<Level> = vec![0];
+            for _ in 1..rng.gen_range(5..=8) {
+                let step = if rng.gen_bool(0.9) { rng.gen_range(1..=3) * direction } else { rng.gen_range(-6..=6) };
+                levels.push(levels[levels.len() - 1] + step);
+            }
+            let lowest = *levels.iter().min().expect("a report has levels");
+            let offset = rng.gen_range(1..=50) - lowest;
+            let report: Vec<_> = levels.iter().map(|level| (level + offset).to_string()).collect();
+            input.push_str(&report.join(" "));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+fn parse_input(input: &str) -> IResult<'_, Vec<Report>> {
+    lines(parse_line)(input)
+}
+
+fn parse_line(input: &str) -> IResult<'_, Report> {
+    context("report", separated_list1(char(' '), map_res(digit1, str::parse)))(input)
+}
+
+#[derive(Debug, Clone)]
+enum Change {
+    Increasing { rate: usize },
+    Decreasing { rate: usize },
+    Stagnant,
+}
+
+impl Change {
+    fn has_valid_rate(&self) -> bool {
+        match self {
+            Change::Increasing { rate } |
+            Change::Decreasing { rate } => rate > &0 && rate <= &3,
+            Change::Stagnant => false,
+        }
+    }
+
+    fn has_same_direction(&self, other: &Self) -> bool {
+        match self {
+            Change::Increasing { .. } => matches!(other, Change::Increasing { .. }),
+            Change::Decreasing { .. } => matches!(other, Change::Decreasing { .. }),
+            Change::Stagnant => matches!(other, Change::Stagnant),
+        }
+    }
+}
+
+impl From<Level> for Change {
+    fn from(value: Level) -> Self {
+        if value == 0 {
+            Change::Stagnant
+        } else if value > 0 {
+            Change::Increasing { rate: value.unsigned_abs() as usize }
+        } else {
+            Change::Decreasing { rate: value.unsigned_abs() as usize }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parse::ParseError;
+    use proptest::prelude::*;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    /// Short reports of close levels, so that safe, dampened and unsafe reports are all common.
+    pub(crate) fn report() -> impl Strategy<Value=Report> {
+        prop::collection::vec(1..10 as Level, 1..8)
+    }
+
+    pub(crate) fn is_safe(report: &[Level]) -> bool {
+        let steps: Vec<_> = report.windows(2).map(|w| w[1] - w[0]).collect();
+        steps.iter().all(|s| (1..=3).contains(s)) || steps.iter().all(|s| (-3..=-1).contains(s))
+    }
+
+    #[test]
+    fn parse_line_test() {
+        // given
+        let input = "7 6 4 2 1";
+
+        // when
+        let (_, vec) = parse_line(input).unwrap();
+
+        // then
+        assert_eq!(vec, vec![7, 6, 4, 2, 1])
+    }
+
+    #[test]
+    fn parse_test() {
+        // given
+        let input = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1";
+
+        // when
+        let (_, vec) = parse_input(input).unwrap();
+
+        // then
+        assert_eq!(vec, vec![
+            vec![7, 6, 4, 2, 1],
+            vec![1, 2, 7, 8, 9],
+            vec![9, 7, 6, 2, 1],
+        ])
+    }
+
+    #[test]
+    fn parse_rejects_malformed_line_test() {
+        // given
+        let input = "7 6 4 2 1
+1 2 x 8 9
+9 7 6 2 1
+";
+
+        // when
+        let err = Day2::parse(input).unwrap_err();
+
+        // then
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (2, 4, "end of line"));
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+        #[test]
+        fn generated_input_has_size_reports_test(size in 1..200usize, seed in any::<u64>()) {
+            // when
+            let reports = parse(&generate::input(size, &mut StdRng::seed_from_u64(seed))).unwrap();
+
+            // then
+            prop_assert_eq!(reports.len(), size);
+            prop_assert!(reports.iter().all(|report| (5..=8).contains(&report.len()) && report.iter().all(|&level| level > 0)));
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use aoc_common::parse::{parse_all, IResult};
+use aoc_common::{Answer, Solution};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete;
+use nom::character::complete::anychar;
+use nom::combinator::map;
+use nom::multi::many0;
+use nom::sequence::Tuple;
+use rand::rngs::StdRng;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    type Input = Vec<Operation>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        part2(input)
+    }
+
+    fn generate(size: usize, rng: &mut StdRng) -> anyhow::Result<String> {
+        Ok(generate::input(size, rng))
+    }
+}
+
+aoc_common::example_tests!(crate::Day3);
+
+/// Picks the instructions out of the corrupted memory, skipping everything else.
+pub fn parse(input: &str) -> anyhow::Result<Vec<Operation>> {
+    Ok(parse_all(input, parse_input)?)
+}
+
+/// Sum of all multiplications.
+pub fn part1(operations: &[Operation]) -> anyhow::Result<Answer> {
+    part1::solution(operations)
+}
+
+/// Sum of the multiplications enabled by the latest `do()` or `don't()` before them.
+pub fn part2(operations: &[Operation]) -> anyhow::Result<Answer> {
+    part2::solution(operations)
+}
+
+mod part1 {
+    use anyhow::Context;
+    use aoc_common::Answer;
+    use crate::Operation;
+
+    pub fn solution(operations: &[Operation]) -> anyhow::Result<Answer> {
+        let sum = operations.iter()
+            .filter_map(|o| match o {
+                Operation::Mul(a, b) => Some((a, b)),
+                _ => None
+            })
+            .try_fold(0u64, |sum, (a, b)| sum.checked_add(a.checked_mul(*b)?))
+            .context("sum of products overflows u64")?;
+        Ok(sum.into())
+    }
+}
+
+mod part2 {
+    use anyhow::Context;
+    use aoc_common::Answer;
+    use crate::Operation;
+
+    pub fn solution(operations: &[Operation]) -> anyhow::Result<Answer> {
+        let mut sum: u64 = 0;
+        let mut is_enabled = true;
+        for operation in operations {
+            match operation {
+                &Operation::Mul(a, b) =>
+                    if is_enabled {
+                        sum = a.checked_mul(b).and_then(|p| sum.checked_add(p)).context("sum of enabled products overflows u64")?
+                    },
+                Operation::Do => is_enabled = true,
+                Operation::Dont => is_enabled = false,
+            }
+        }
+        Ok(sum.into())
+    }
+}
+
+pub mod generate {
+    use rand::seq::SliceRandom;
+    use rand::Rng;
+
+    /// Junk found between the instructions of the real input, including instructions that are
+    /// almost but not quite valid.
+    const NOISE: [&str; 18] = [
+        "what()", "who()", "from()", "select()", "when()", "where()", "why()", "how()", "mul[3,7]", "mul(4*",
+        "mul ( 2 , 4 )", "do_not_", "?", "'", "<", "}", "%&", "(mul(32,64]",
+    ];
+
+    /// `size` instructions, most of them `mul`s, buried in corrupted memory.
+    pub fn input(size: usize, rng: &mut impl Rng) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            for _ in 0..rng.gen_range(0..4) {
+                input.push_str(NOISE.choose(rng).expect("there is noise"));
+            }
+            match rng.gen_range(0..10) {
+                0 => input.push_str("do()"),
+                1 => input.push_str("don't()"),
+                _ => input.push_str(&format!("mul({},{})", rng.gen_range(1..1000), rng.gen_range(1..1000))),
+            }
+        }
+        input.push('\n');
+        input
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Operation {
+    Mul(u64, u64),
+    Do,
+    Dont,
+}
+
+fn parse_input(input: &str) -> IResult<'_, Vec<Operation>> {
+    let (input, maybe_operations) = many0(alt((
+        parse_mul,
+        map(tag("do()"), |_| Some(Operation::Do)),
+        map(tag("don't()"), |_| Some(Operation::Dont)),
+        map(anychar, |_| None))))(input)?;
+    Ok((input, maybe_operations.into_iter().flatten().collect::<Vec<_>>()))
+}
+
+fn parse_mul(input: &str) -> IResult<'_, Option<Operation>> {
+    let (input, (_, a, _, b, _)) = (tag("mul("), complete::u64, tag(","), complete::u64, tag(")")).parse(input)?;
+    Ok((input, Some(Operation::Mul(a, b))))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn parse_part1_test() {
+        // given
+        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)";
+
+        // when
+        let (_, operation) = parse_input(input).unwrap();
+
+        // then
+        assert_eq!(operation, vec![Operation::Mul(2, 4), Operation::Mul(5, 5)]);
+    }
+
+    #[test]
+    fn parse_part2_test() {
+        // given
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+        // when
+        let (_, operation) = parse_input(input).unwrap();
+
+        // then
+        assert_eq!(operation, vec![Operation::Mul(2, 4), Operation::Dont, Operation::Mul(5, 5), Operation::Mul(11, 8), Operation::Do, Operation::Mul(8, 5)]);
+    }
+
+    /// Corrupted memory glued together from fragments of instructions.
+    fn memory() -> impl Strategy<Value=String> {
+        let fragment = prop_oneof![
+            Just("mul(".to_owned()), Just(",".to_owned()), Just(")".to_owned()), Just("do()".to_owned()),
+            Just("don't()".to_owned()), Just("mul".to_owned()), Just("(".to_owned()), Just("x".to_owned()),
+            (0..1000u64).prop_map(|n| n.to_string()),
+        ];
+        prop::collection::vec(fragment, 0..40)
+            .prop_map(|fragments| fragments.concat())
+            // keeps products of adjacent numbers within u64
+            .prop_filter("too many digits in a row", |memory| memory.split(|c: char| !c.is_ascii_digit()).all(|digits| digits.len() <= 9))
+    }
+
+    /// Tries every instruction at every offset of the memory, returning the sum of all products
+    /// and the sum of the enabled ones.
+    fn scan_oracle(memory: &str) -> (u64, u64) {
+        let (mut all, mut enabled_sum, mut enabled) = (0, 0, true);
+        for i in 0..memory.len() {
+            let rest = &memory[i..];
+            if rest.starts_with("do()") {
+                enabled = true;
+            } else if rest.starts_with("don't()") {
+                enabled = false;
+            } else if let Some(args) = rest.strip_prefix("mul(").and_then(|r| r.split_once(')')) {
+                let Some((a, b)) = args.0.split_once(',') else { continue };
+                let number = |n: &str| (!n.is_empty() && n.chars().all(|c| c.is_ascii_digit())).then(|| n.parse::<u64>().unwrap());
+                if let (Some(a), Some(b)) = (number(a), number(b)) {
+                    all += a * b;
+                    if enabled {
+                        enabled_sum += a * b;
+                    }
+                }
+            }
+        }
+        (all, enabled_sum)
+    }
+
+    proptest! {
+        #[test]
+        fn solution_matches_oracle_test(memory in memory()) {
+            // given
+            let (part1, part2) = scan_oracle(&memory);
+
+            // when
+            let operations = Day3::parse(&memory).unwrap();
+
+            // then
+            prop_assert_eq!(Day3::part1(&operations).unwrap(), Answer::from(part1));
+            prop_assert_eq!(Day3::part2(&operations).unwrap(), Answer::from(part2));
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+        #[test]
+        fn generated_input_has_size_instructions_test(size in 1..200usize, seed in any::<u64>()) {
+            // when
+            let operations = parse(&generate::input(size, &mut StdRng::seed_from_u64(seed))).unwrap();
+
+            // then
+            prop_assert_eq!(operations.len(), size);
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use aoc_common::{Answer, Grid, Point, Solution};
+use rand::rngs::StdRng;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        part2(input)
+    }
+
+    fn generate(size: usize, rng: &mut StdRng) -> anyhow::Result<String> {
+        Ok(generate::input(size, rng))
+    }
+}
+
+aoc_common::example_tests!(crate::Day4);
+
+/// Parses the word search.
+pub fn parse(input: &str) -> anyhow::Result<Grid<char>> {
+    Ok(input.parse()?)
+}
+
+/// Number of times `XMAS` appears in any of the eight directions.
+pub fn part1(grid: &Grid<char>) -> anyhow::Result<Answer> {
+    part1::solution(grid)
+}
+
+/// Number of `MAS` crosses.
+pub fn part2(grid: &Grid<char>) -> anyhow::Result<Answer> {
+    part2::solution(grid)
+}
+
+mod part1 {
+    use aoc_common::{Answer, Direction8, Grid, Point};
+
+    //   ______> x
+    //   |
+    //   |
+    //  \/
+    //   y
+
+    const WORD: &str = "XMAS";
+
+    pub fn solution(grid: &Grid<char>) -> anyhow::Result<Answer> {
+        let occurrences: usize = grid.positions()
+            .map(|pos| Direction8::all().filter(|&dir| match_word(grid, pos, dir)).count())
+            .sum();
+        Ok(occurrences.into())
+    }
+
+    fn match_word(grid: &Grid<char>, pos: Point, dir: Direction8) -> bool {
+        WORD.chars().zip(0..)
+            .all(|(c, step)| grid.get(pos + dir * step) == Some(&c))
+    }
+}
+
+mod part2 {
+    use aoc_common::{Answer, Grid, Point};
+
+    use crate::match_pattern;
+
+    pub fn solution(grid: &Grid<char>) -> anyhow::Result<Answer> {
+        let patterns = [
+            [[('M', -1, -1), ('A', 0, 0), ('S', 1, 1)], [('M', -1, 1), ('A', 0, 0), ('S', 1, -1)]],
+            [[('M', -1, -1), ('A', 0, 0), ('S', 1, 1)], [('M', 1, -1), ('A', 0, 0), ('S', -1, 1)]],
+            [[('M', 1, -1), ('A', 0, 0), ('S', -1, 1)], [('M', 1, 1), ('A', 0, 0), ('S', -1, -1)]],
+            [[('M', -1, 1), ('A', 0, 0), ('S', 1, -1)], [('M', 1, 1), ('A', 0, 0), ('S', -1, -1)],
+            ]
+        ];
+        let mut occurrences = 0;
+        for pos in grid.positions() {
+            for sub_patters in patterns {
+                if match_all_pattern(pos, &sub_patters, grid) {
+                    occurrences += 1;
+                }
+            }
+        }
+        Ok(occurrences.into())
+    }
+
+    fn match_all_pattern(pos: Point, patterns: &[[(char, isize, isize); 3]; 2], grid: &Grid<char>) -> bool {
+        for pattern in patterns {
+            if !match_pattern(pos, grid, pattern) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+pub mod generate {
+    use rand::seq::SliceRandom;
+    use rand::Rng;
+
+    /// A `size` by `size` word search of random `X`, `M`, `A` and `S`.
+    pub fn input(size: usize, rng: &mut impl Rng) -> String {
+        let mut input = String::with_capacity(size * (size + 1));
+        for _ in 0..size {
+            input.extend((0..size).map(|_| *['X', 'M', 'A', 'S'].choose(rng).expect("there are letters")));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+fn match_pattern(pos: Point, grid: &Grid<char>, pattern: &[(char, isize, isize)]) -> bool {
+    for &(c, x_offset, y_offset) in pattern {
+        if grid.get(pos + Point::new(x_offset, y_offset)) != Some(&c) {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    fn rows() -> impl Strategy<Value=Vec<String>> {
+        (1..10usize, 1..10usize).prop_flat_map(|(width, height)| {
+            let row = prop::collection::vec(prop::sample::select(vec!['X', 'M', 'A', 'S']), width).prop_map(String::from_iter);
+            prop::collection::vec(row, height)
+        })
+    }
+
+    /// Counts `XMAS` forwards and backwards in every row, column and diagonal read as a string.
+    fn xmas_oracle(rows: &[Vec<char>]) -> usize {
+        let (width, height) = (rows[0].len() as isize, rows.len() as isize);
+        let cell = |x: isize, y: isize| rows[y as usize][x as usize];
+        let mut lines: Vec<String> = vec![];
+        lines.extend((0..height).map(|y| (0..width).map(|x| cell(x, y)).collect::<String>()));
+        lines.extend((0..width).map(|x| (0..height).map(|y| cell(x, y)).collect::<String>()));
+        for d in -height..width + height {
+            lines.push((0..height).filter(|y| (0..width).contains(&(y + d))).map(|y| cell(y + d, y)).collect());
+            lines.push((0..height).filter(|y| (0..width).contains(&(d - y))).map(|y| cell(d - y, y)).collect());
+        }
+        lines.iter().map(|line| line.matches("XMAS").count() + line.matches("SAMX").count()).sum()
+    }
+
+    /// Counts the `A`s whose both diagonals hold one `M` and one `S`.
+    fn x_mas_oracle(rows: &[Vec<char>]) -> usize {
+        let mut count = 0;
+        for y in 1..rows.len().saturating_sub(1) {
+            for x in 1..rows[0].len().saturating_sub(1) {
+                let diagonal = |a: char, b: char| matches!((a, b), ('M', 'S') | ('S', 'M'));
+                if rows[y][x] == 'A'
+                    && diagonal(rows[y - 1][x - 1], rows[y + 1][x + 1])
+                    && diagonal(rows[y - 1][x + 1], rows[y + 1][x - 1]) {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    proptest! {
+        #[test]
+        fn solution_matches_oracle_test(rows in rows()) {
+            // given
+            let chars: Vec<Vec<char>> = rows.iter().map(|row| row.chars().collect()).collect();
+
+            // when
+            let grid = Day4::parse(&rows.join("\n")).unwrap();
+
+            // then
+            prop_assert_eq!(Day4::part1(&grid).unwrap(), Answer::from(xmas_oracle(&chars)));
+            prop_assert_eq!(Day4::part2(&grid).unwrap(), Answer::from(x_mas_oracle(&chars)));
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+        #[test]
+        fn generated_input_is_a_square_word_search_test(size in 1..30usize, seed in any::<u64>()) {
+            // when
+            let grid = parse(&generate::input(size, &mut StdRng::seed_from_u64(seed))).unwrap();
+
+            // then
+            prop_assert_eq!((grid.width(), grid.height()), (size, size));
+            prop_assert!(grid.iter().all(|(_, letter)| "XMAS".contains(*letter)));
+        }
+    }
+}
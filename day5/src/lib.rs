@@ -0,0 +1,438 @@
+use aoc_common::parse::{lines, parse_all, IResult};
+use aoc_common::{Answer, Solution};
+use nom::character::complete;
+use nom::character::complete::{char, line_ending};
+use nom::error::context;
+use nom::multi::{many0, separated_list1};
+use nom::sequence::Tuple;
+use rand::rngs::StdRng;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    type Input = (Vec<Rule>, Vec<PrintUpdate>);
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1((rules, print_updates): &Self::Input) -> anyhow::Result<Answer> {
+        part1(rules, print_updates)
+    }
+
+    fn part2((rules, print_updates): &Self::Input) -> anyhow::Result<Answer> {
+        part2(rules, print_updates)
+    }
+
+    fn generate(size: usize, rng: &mut StdRng) -> anyhow::Result<String> {
+        Ok(generate::input(size, rng))
+    }
+}
+
+aoc_common::example_tests!(crate::Day5);
+
+/// Parses the page ordering rules and, after a blank line, the updates.
+pub fn parse(input: &str) -> anyhow::Result<(Vec<Rule>, Vec<PrintUpdate>)> {
+    Ok(parse_all(input, parse_input)?)
+}
+
+/// Sum of the middle pages of the updates already in the right order.
+pub fn part1(rules: &[Rule], print_updates: &[PrintUpdate]) -> anyhow::Result<Answer> {
+    part1::solution(rules, print_updates)
+}
+
+/// Sum of the middle pages of the wrongly ordered updates, once put in order.
+pub fn part2(rules: &[Rule], print_updates: &[PrintUpdate]) -> anyhow::Result<Answer> {
+    part2::solution(rules, print_updates)
+}
+
+mod part1 {
+    use anyhow::Context;
+    use aoc_common::Answer;
+    use crate::{PageNumber, PrintUpdate, Rule};
+
+    pub fn solution(rules: &[Rule], print_updates: &[PrintUpdate]) -> anyhow::Result<Answer> {
+        let sum = print_updates.iter().filter_map(|p| verify_print_update(rules, p))
+            .try_fold(0, PageNumber::checked_add)
+            .context("sum of middle pages overflows u64")?;
+        Ok(sum.into())
+    }
+
+    fn verify_print_update(rules: &[Rule], print_update: &PrintUpdate) -> Option<PageNumber> {
+        let pages_combination: Vec<_> = print_update.pages
+            .iter().enumerate()
+            .flat_map(|(i, &p)| print_update.pages[i + 1..].iter()
+                .map(move |&op| (p, op))
+            )
+            .collect();
+        let matches = pages_combination.iter().all(|(left, right)| rules.iter().all(|r| r.apply(left, right).unwrap_or(true)));
+
+        if matches {
+            Some(print_update.pages[print_update.pages.len() / 2])
+        } else {
+            None
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn verify_print_update_test() {
+            // given
+            let rules = vec![
+                Rule(75, 47), Rule(75, 61), Rule(75, 53), Rule(75, 29),
+                Rule(47, 61), Rule(47, 53), Rule(47, 29),
+                Rule(61, 53), Rule(61, 29),
+                Rule(53, 29),
+            ];
+            let print_uprate = PrintUpdate { pages: vec![75, 47, 61, 53, 29] };
+
+            // when
+            let result = verify_print_update(&rules, &print_uprate);
+
+            // then
+            assert_eq!(result, Some(61));
+        }
+    }
+}
+
+mod part2 {
+    use std::collections::HashSet;
+
+    use anyhow::Context;
+    use aoc_common::Answer;
+    use crate::{PageNumber, PrintUpdate, Rule};
+
+    pub fn solution(rules: &[Rule], print_updates: &[PrintUpdate]) -> anyhow::Result<Answer> {
+        let mut sum: PageNumber = 0;
+        for print_update in print_updates {
+            if let Some(page) = fix_print_update(rules, print_update.clone())? {
+                sum = sum.checked_add(page).context("sum of middle pages overflows u64")?;
+            }
+        }
+        Ok(sum.into())
+    }
+
+    fn fix_print_update(rules: &[Rule], mut print_update: PrintUpdate) -> anyhow::Result<Option<PageNumber>> {
+        let mut is_first = true;
+        let mut seen = HashSet::new();
+        loop {
+            // contradicting rules can send the swaps round in circles
+            anyhow::ensure!(seen.insert(print_update.pages.clone()), "rules contradict each other on update {:?}", print_update.pages);
+            let pages_combination: Vec<_> = print_update.pages
+                .iter().enumerate()
+                .flat_map(|(i, &p)| print_update.pages[i + 1..].iter().enumerate()
+                    .map(move |(j, &op)| ((i, p), (i + 1 + j, op)))
+                )
+                .collect();
+            let matches: Vec<_> = pages_combination.iter()
+                .filter(|((_, left), (_, right))| !rules.iter().all(|r| r.apply(left, right).unwrap_or(true)))
+                .collect();
+            if matches.is_empty() {
+                return Ok(if is_first {
+                    None
+                } else {
+                    Some(print_update.pages[print_update.pages.len() / 2])
+                })
+            }
+            is_first = false;
+            let [((i, _), (j, _)), ..] = matches.as_slice() else { unreachable!() };
+            print_update.pages.swap(*i, *j);
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use proptest::prelude::*;
+
+        use super::*;
+
+        #[test]
+        fn fix_print_update_test() {
+            // given
+            let rules = vec![
+                Rule(97, 75),
+                Rule(75, 47), Rule(75, 61), Rule(75, 53), Rule(75, 29),
+                Rule(47, 61), Rule(47, 53), Rule(47, 29),
+                Rule(61, 53), Rule(61, 29),
+                Rule(53, 29),
+            ];
+            let print_uprate = PrintUpdate { pages: vec![75, 97, 47, 61, 53] };
+
+            // when
+            let result = fix_print_update(&rules, print_uprate).unwrap();
+
+            // then
+            assert_eq!(result, Some(47));
+        }
+
+        #[test]
+        fn fix_print_update_with_contradicting_rules_test() {
+            // given
+            let rules = vec![Rule(61, 75), Rule(75, 61)];
+            let print_uprate = PrintUpdate { pages: vec![75, 61, 61] };
+
+            // when
+            let result = fix_print_update(&rules, print_uprate);
+
+            // then
+            assert!(result.is_err());
+        }
+
+        proptest! {
+            #[test]
+            fn fix_print_update_matches_topological_sort_test((rules, updates) in crate::tests::rules_and_updates()) {
+                for update in updates {
+                    // given
+                    let order = crate::tests::topological_order(&rules, &update.pages);
+                    let expected = (order != update.pages).then(|| order[order.len() / 2]);
+
+                    // when
+                    let result = fix_print_update(&rules, update).unwrap();
+
+                    // then
+                    prop_assert_eq!(result, expected);
+                }
+            }
+        }
+    }
+}
+
+pub mod generate {
+    use rand::seq::{IteratorRandom, SliceRandom};
+    use rand::Rng;
+
+    use crate::PageNumber;
+
+    /// Rules putting 49 two digit pages in a single total order, like the real input, followed by
+    /// `size` updates of five to 23 of those pages. About half of the updates are in order.
+    pub fn input(size: usize, rng: &mut impl Rng) -> String {
+        let mut order: Vec<PageNumber> = (10..100).choose_multiple(rng, 49);
+        order.shuffle(rng);
+        let mut rules: Vec<_> = order.iter().enumerate()
+            .flat_map(|(i, before)| order[i + 1..].iter().map(move |after| format!("{before}|{after}\n")))
+            .collect();
+        rules.shuffle(rng);
+
+        let mut input = rules.concat();
+        input.push('\n');
+        for _ in 0..size {
+            let len = rng.gen_range(2..=11) * 2 + 1;
+            let mut pages = order.choose_multiple(rng, len).copied().collect::<Vec<_>>();
+            if rng.gen_bool(0.5) {
+                pages.sort_by_key(|page| order.iter().position(|p| p == page));
+            }
+            let pages: Vec<_> = pages.iter().map(PageNumber::to_string).collect();
+            input.push_str(&pages.join(","));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+pub type PageNumber = u64;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// The first page must be printed before the second when an update contains both.
+pub struct Rule(pub PageNumber, pub PageNumber);
+
+impl Rule {
+    /// Whether `left` may come before `right`, or `None` when the rule is about other pages.
+    pub fn apply(&self, left: &PageNumber, right: &PageNumber) -> Option<bool> {
+        if *left == self.0 && *right == self.1 {
+            Some(true)
+        } else if *left == self.1 && *right == self.0 {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PrintUpdate {
+    pub pages: Vec<PageNumber>,
+}
+
+fn parse_input(input: &str) -> IResult<'_, (Vec<Rule>, Vec<PrintUpdate>)> {
+    let (input, rules) = lines(parse_rule)(input)?;
+    let (input, _) = many0(line_ending)(input)?;
+    let (input, print_updates) = lines(parse_page_update)(input)?;
+    Ok((input, (rules, print_updates)))
+}
+
+fn parse_rule(input: &str) -> IResult<'_, Rule> {
+    let (input, (left, _, right)) = context("page-ordering rule", |i| (complete::u64, char('|'), complete::u64).parse(i))(input)?;
+    Ok((input, Rule(left, right)))
+}
+
+fn parse_page_update(input: &str) -> IResult<'_, PrintUpdate> {
+    let (input, pages) = context("page update", separated_list1(char(','), complete::u64))(input)?;
+    Ok((input, PrintUpdate { pages }))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parse::ParseError;
+    use proptest::prelude::*;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    /// Rules ordering every pair of some pages, as in the puzzle input, and updates of an odd
+    /// number of those pages in any order.
+    pub(crate) fn rules_and_updates() -> impl Strategy<Value=(Vec<Rule>, Vec<PrintUpdate>)> {
+        let order = prop::sample::subsequence((10..100).collect::<Vec<PageNumber>>(), 2..10).prop_shuffle();
+        order.prop_flat_map(|order| {
+            let rules: Vec<_> = order.iter().enumerate()
+                .flat_map(|(i, &before)| order[i + 1..].iter().map(move |&after| Rule(before, after)))
+                .collect();
+            let update = prop::sample::subsequence(order.clone(), 1..=order.len())
+                .prop_shuffle()
+                .prop_map(|mut pages| {
+                    pages.truncate((pages.len() - 1) | 1);
+                    PrintUpdate { pages }
+                });
+            (Just(rules), prop::collection::vec(update, 1..10))
+        })
+    }
+
+    /// Orders the pages of an update with Kahn's algorithm, taking only the rules between them.
+    pub(crate) fn topological_order(rules: &[Rule], pages: &[PageNumber]) -> Vec<PageNumber> {
+        let rules: Vec<_> = rules.iter().filter(|r| pages.contains(&r.0) && pages.contains(&r.1)).collect();
+        let mut remaining = pages.to_vec();
+        let mut order = vec![];
+        while !remaining.is_empty() {
+            let next = remaining.iter().position(|&p| !rules.iter().any(|r| r.1 == p && remaining.contains(&r.0)))
+                .expect("rules contain a cycle");
+            order.push(remaining.remove(next));
+        }
+        order
+    }
+
+    proptest! {
+        #[test]
+        fn solution_matches_oracle_test((rules, updates) in rules_and_updates()) {
+            // given
+            let input = format!(
+                "{}\n\n{}\n",
+                rules.iter().map(|Rule(a, b)| format!("{a}|{b}")).collect::<Vec<_>>().join("\n"),
+                updates.iter().map(|u| u.pages.iter().map(PageNumber::to_string).collect::<Vec<_>>().join(",")).collect::<Vec<_>>().join("\n"),
+            );
+            let (mut ordered, mut fixed) = (0, 0);
+            for update in &updates {
+                let order = topological_order(&rules, &update.pages);
+                if order == update.pages {
+                    ordered += order[order.len() / 2];
+                } else {
+                    fixed += order[order.len() / 2];
+                }
+            }
+
+            // when
+            let parsed = Day5::parse(&input).unwrap();
+
+            // then
+            prop_assert_eq!(Day5::part1(&parsed).unwrap(), Answer::from(ordered));
+            prop_assert_eq!(Day5::part2(&parsed).unwrap(), Answer::from(fixed));
+        }
+    }
+
+    #[test]
+    fn parse_rule_test() {
+        // given
+        let input = "47|53";
+
+        // when
+        let (input, rule) = parse_rule(input).unwrap();
+
+        // then
+        assert!(input.is_empty());
+        assert_eq!(rule, Rule(47, 53));
+    }
+
+    #[test]
+    fn parse_page_update_test() {
+        // given
+        let input = "75,47,61,53,29";
+
+        // when
+        let (input, rule) = parse_page_update(input).unwrap();
+
+        // then
+        assert!(input.is_empty());
+        assert_eq!(rule, PrintUpdate { pages: vec![75, 47, 61, 53, 29] });
+    }
+
+    #[test]
+    fn parse_test() {
+        // given
+        let input = "47|53
+97|13
+53|13
+
+75,47,61,53,29
+61,13,29
+97,13,75,29,47";
+
+        // when
+        let (input, (rules, print_updates)) = parse_input(input).unwrap();
+
+        // then
+        assert!(input.is_empty());
+        assert_eq!(rules, vec![
+            Rule(47, 53),
+            Rule(97, 13),
+            Rule(53, 13)]
+        );
+        assert_eq!(print_updates, vec![
+            PrintUpdate { pages: vec![75, 47, 61, 53, 29] },
+            PrintUpdate { pages: vec![61, 13, 29] },
+            PrintUpdate { pages: vec![97, 13, 75, 29, 47] }]
+        );
+    }
+
+    #[test]
+    fn parse_rejects_malformed_rule_test() {
+        // given
+        let input = "47|53
+97-13
+53|13
+
+75,47,61,53,29
+";
+
+        // when
+        let err = Day5::parse(input).unwrap_err();
+
+        // then
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!(err.to_string(), "2:3: expected `|` in page-ordering rule
+2 | 97-13
+  |   ^");
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+        #[test]
+        fn generated_updates_are_fully_ordered_test(size in 1..50usize, seed in any::<u64>()) {
+            // when
+            let (rules, updates) = parse(&generate::input(size, &mut StdRng::seed_from_u64(seed))).unwrap();
+
+            // then
+            prop_assert_eq!(updates.len(), size);
+            for update in &updates {
+                prop_assert!((5..=23).contains(&update.pages.len()) && update.pages.len() % 2 == 1);
+                for (i, &before) in update.pages.iter().enumerate() {
+                    for &after in &update.pages[i + 1..] {
+                        prop_assert!(rules.contains(&Rule(before, after)) || rules.contains(&Rule(after, before)));
+                    }
+                }
+            }
+        }
+    }
+}
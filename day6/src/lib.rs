@@ -0,0 +1,337 @@
+use aoc_common::{Answer, Direction4, Grid, Point, Solution};
+use rand::rngs::StdRng;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        part2(input)
+    }
+
+    fn generate(size: usize, rng: &mut StdRng) -> anyhow::Result<String> {
+        Ok(generate::input(size, rng))
+    }
+}
+
+aoc_common::example_tests!(crate::Day6);
+
+/// Parses the lab map, the guard being one of `^`, `>`, `v` or `<`.
+pub fn parse(input: &str) -> anyhow::Result<Grid<char>> {
+    Ok(input.parse()?)
+}
+
+/// Number of distinct positions the guard visits before leaving the lab.
+pub fn part1(grid: &Grid<char>) -> anyhow::Result<Answer> {
+    part1::solution(grid)
+}
+
+/// Number of positions where a new obstruction would trap the guard in a loop.
+pub fn part2(grid: &Grid<char>) -> anyhow::Result<Answer> {
+    part2::solution(grid)
+}
+
+mod part1 {
+    use std::collections::HashSet;
+
+    use anyhow::Context;
+
+    use aoc_common::{Answer, Grid};
+
+    use crate::find_guard_initial_pos_and_dir;
+
+    pub fn solution(grid: &Grid<char>) -> anyhow::Result<Answer> {
+        let (mut guard_pos, mut guard_dir) = find_guard_initial_pos_and_dir(grid).context("failed to find guard on the grid")?;
+        let mut visited_pos = HashSet::new();
+        let mut visited_states = HashSet::new();
+
+        'outer: loop {
+            visited_pos.insert(guard_pos);
+
+            'inner: loop {
+                anyhow::ensure!(visited_states.insert((guard_pos, guard_dir)), "the guard never leaves the lab");
+                let next_pos = guard_pos + guard_dir;
+                let Some(&next) = grid.get(next_pos) else {
+                    break 'outer;
+                };
+                if next == '#' {
+                    guard_dir = guard_dir.turn_right();
+                } else {
+                    guard_pos = next_pos;
+                    break 'inner;
+                }
+            }
+        }
+        Ok(visited_pos.len().into())
+    }
+}
+
+mod part2 {
+    use std::collections::HashSet;
+
+    use anyhow::Context;
+
+    use aoc_common::{Answer, Grid};
+
+    use crate::find_guard_initial_pos_and_dir;
+
+    pub fn solution(grid: &Grid<char>) -> anyhow::Result<Answer> {
+        let (mut guard_pos, mut guard_dir) = find_guard_initial_pos_and_dir(grid).context("failed to find guard on the grid")?;
+        let mut visited_pos = HashSet::new();
+        let mut visited_states = HashSet::new();
+        let mut placed_hashes = HashSet::new();
+        'outer: loop {
+            visited_pos.insert(guard_pos);
+
+            'inner: loop {
+                anyhow::ensure!(visited_states.insert((guard_pos, guard_dir)), "the guard never leaves the lab");
+                let next_pos = guard_pos + guard_dir;
+                let Some(&next) = grid.get(next_pos) else {
+                    break 'outer;
+                };
+                if next == '#' {
+                    guard_dir = guard_dir.turn_right();
+                    continue 'inner;
+                }
+                // an obstacle on a cell the guard already crossed would have changed the path before here
+                if !visited_pos.contains(&next_pos) {
+                    let mut cloned_grid = grid.clone();
+                    cloned_grid[next_pos] = 'X';
+                    let mut cloned_guard_pos = guard_pos;
+                    let mut cloned_guard_dir = guard_dir;
+                    let mut cloned_visited_pos = HashSet::new();
+                    'checking: loop {
+                        if !cloned_visited_pos.insert((cloned_guard_pos, cloned_guard_dir)) {
+                            placed_hashes.insert(next_pos);
+                            break 'checking;
+                        }
+                        let cloned_next_pos = cloned_guard_pos + cloned_guard_dir;
+                        let Some(&char) = cloned_grid.get(cloned_next_pos) else {
+                            break 'checking;
+                        };
+                        if char == '#' || char == 'X' {
+                            cloned_guard_dir = cloned_guard_dir.turn_right();
+                        } else {
+                            cloned_guard_pos = cloned_next_pos;
+                        }
+                    }
+                }
+                guard_pos = next_pos;
+                break 'inner;
+            }
+        }
+
+        Ok(placed_hashes.len().into())
+    }
+}
+
+pub mod generate {
+    use rand::Rng;
+
+    const DIRECTIONS: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+    /// A `size` by `size` lab with obstacles on about one cell in twenty, as dense as the real
+    /// input. Obstacles that would keep the guard walking in circles are removed until it leaves.
+    /// The lab is at least one cell, the guard's own.
+    pub fn input(size: usize, rng: &mut impl Rng) -> String {
+        let size = size.max(1);
+        let mut cells: Vec<u8> = (0..size * size).map(|_| if rng.gen_bool(0.05) { b'#' } else { b'.' }).collect();
+        let start = rng.gen_range(0..cells.len());
+        cells[start] = b'^';
+        let mut seen = vec![0; cells.len()];
+        while let Some(obstacle) = loop_obstacle(&cells, size, start, &mut seen) {
+            cells[obstacle] = b'.';
+        }
+
+        let mut input = String::with_capacity(size * (size + 1));
+        for row in cells.chunks(size) {
+            input.push_str(std::str::from_utf8(row).expect("cells are ascii"));
+            input.push('\n');
+        }
+        input
+    }
+
+    /// Walks the guard from `start` and returns one of the obstacles it keeps bumping into, or
+    /// `None` when it leaves the lab. `seen` holds the directions the guard faced on every cell
+    /// and is cleared again before returning.
+    fn loop_obstacle(cells: &[u8], size: usize, start: usize, seen: &mut [u8]) -> Option<usize> {
+        let mut path = vec![];
+        let obstacle = walk(cells, size, start, seen, &mut path);
+        for pos in path {
+            seen[pos] = 0;
+        }
+        obstacle
+    }
+
+    fn walk(cells: &[u8], size: usize, start: usize, seen: &mut [u8], path: &mut Vec<usize>) -> Option<usize> {
+        let (mut pos, mut dir) = (start, 0);
+        let mut looping = false;
+        loop {
+            path.push(pos);
+            looping |= seen[pos] & 1 << dir != 0;
+            seen[pos] |= 1 << dir;
+            let (dx, dy) = DIRECTIONS[dir];
+            let x = (pos % size).checked_add_signed(dx).filter(|&x| x < size)?;
+            let y = (pos / size).checked_add_signed(dy).filter(|&y| y < size)?;
+            let next = y * size + x;
+            if cells[next] != b'#' {
+                pos = next;
+            } else if looping {
+                return Some(next);
+            } else {
+                dir = (dir + 1) % 4;
+            }
+        }
+    }
+}
+
+fn find_guard_initial_pos_and_dir(grid: &Grid<char>) -> Option<(Point, Direction4)> {
+    grid.iter()
+        .find_map(|(pos, c)|
+            match c {
+                '>' => Some((pos, Direction4::Right)),
+                '^' => Some((pos, Direction4::Up)),
+                'v' => Some((pos, Direction4::Down)),
+                '<' => Some((pos, Direction4::Left)),
+                _ => None
+            })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use proptest::prelude::*;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    /// Obstacles, the guard's start and its direction, on a small map.
+    type Lab = (Vec<Vec<bool>>, (usize, usize), usize);
+
+    const DIRECTIONS: [(isize, isize, char); 4] = [(0, -1, '^'), (1, 0, '>'), (0, 1, 'v'), (-1, 0, '<')];
+
+    /// Walks the guard one step or turn at a time, returning the visited cells, or `None` when it
+    /// never leaves the map.
+    fn walk_oracle((obstacles, start, dir): &Lab) -> Option<HashSet<(usize, usize)>> {
+        let (mut pos, mut dir) = (*start, *dir);
+        let mut states = HashSet::new();
+        while states.insert((pos, dir)) {
+            let (dx, dy, _) = DIRECTIONS[dir];
+            let (x, y) = (pos.0 as isize + dx, pos.1 as isize + dy);
+            let Some(&blocked) = obstacles.get(y as usize).and_then(|row| row.get(x as usize)) else {
+                return Some(states.into_iter().map(|(pos, _)| pos).collect());
+            };
+            if blocked {
+                dir = (dir + 1) % 4;
+            } else {
+                pos = (x as usize, y as usize);
+            }
+        }
+        None
+    }
+
+    /// Tries an extra obstacle on every free cell but the start.
+    fn obstruction_oracle(lab: &Lab) -> usize {
+        let (obstacles, start, dir) = lab;
+        let mut count = 0;
+        for y in 0..obstacles.len() {
+            for x in 0..obstacles[0].len() {
+                if obstacles[y][x] || (x, y) == *start {
+                    continue;
+                }
+                let mut obstructed = obstacles.clone();
+                obstructed[y][x] = true;
+                if walk_oracle(&(obstructed, *start, *dir)).is_none() {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// Labs the guard leaves, as the puzzle input guarantees.
+    fn lab() -> impl Strategy<Value=Lab> {
+        (1..10usize, 1..10usize)
+            .prop_flat_map(|(width, height)| (
+                prop::collection::vec(prop::collection::vec(prop::bool::weighted(0.2), width), height),
+                (0..width, 0..height),
+                0..4usize,
+            ))
+            .prop_map(|(mut obstacles, (x, y), dir)| {
+                obstacles[y][x] = false;
+                (obstacles, (x, y), dir)
+            })
+            .prop_filter("the guard never leaves", |lab| walk_oracle(lab).is_some())
+    }
+
+    proptest! {
+        #[test]
+        fn solution_matches_oracle_test(lab in lab()) {
+            // given
+            let (obstacles, start, dir) = &lab;
+            let input: Vec<String> = obstacles.iter().enumerate()
+                .map(|(y, row)| row.iter().enumerate()
+                    .map(|(x, &blocked)| if (x, y) == *start { DIRECTIONS[*dir].2 } else if blocked { '#' } else { '.' })
+                    .collect())
+                .collect();
+
+            // when
+            let grid = Day6::parse(&input.join("\n")).unwrap();
+
+            // then
+            prop_assert_eq!(Day6::part1(&grid).unwrap(), Answer::from(walk_oracle(&lab).unwrap().len()));
+            prop_assert_eq!(Day6::part2(&grid).unwrap(), Answer::from(obstruction_oracle(&lab)));
+        }
+    }
+
+    #[test]
+    fn find_guard_initial_pos_and_dir_test() {
+        // given
+        let grid: Grid<char> = "..#\n.<.\n...".parse().unwrap();
+
+        // when
+        let guard = find_guard_initial_pos_and_dir(&grid);
+
+        // then
+        assert_eq!(guard, Some((Point::new(1, 1), Direction4::Left)));
+    }
+
+    #[test]
+    fn guard_never_leaving_is_an_error_test() {
+        // given
+        let looping: Grid<char> = ".#..\n...#\n.^..\n#...\n..#.".parse().unwrap();
+        let boxed_in: Grid<char> = ".#.\n#^#\n.#.".parse().unwrap();
+
+        // then
+        for grid in [looping, boxed_in] {
+            assert!(Day6::part1(&grid).is_err());
+            assert!(Day6::part2(&grid).is_err());
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+        #[test]
+        fn generated_lab_lets_the_guard_leave_test(size in 0..30usize, seed in any::<u64>()) {
+            // when
+            let grid = parse(&generate::input(size, &mut StdRng::seed_from_u64(seed))).unwrap();
+
+            // then
+            prop_assert_eq!((grid.width(), grid.height()), (size.max(1), size.max(1)));
+            prop_assert_eq!(grid.iter().filter(|&(_, &cell)| cell == '^').count(), 1);
+            prop_assert!(part1(&grid).is_ok());
+        }
+    }
+}
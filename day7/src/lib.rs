@@ -0,0 +1,336 @@
+use aoc_common::parse::{lines, parse_all, IResult};
+use aoc_common::{Answer, Solution};
+use nom::character::complete;
+use nom::character::complete::char;
+use nom::error::context;
+use nom::multi::many1;
+use nom::sequence::Tuple;
+use rand::rngs::StdRng;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        part2(input)
+    }
+
+    fn generate(size: usize, rng: &mut StdRng) -> anyhow::Result<String> {
+        Ok(generate::input(size, rng))
+    }
+}
+
+aoc_common::example_tests!(crate::Day7);
+
+/// Parses the calibration equations, one per line.
+pub fn parse(input: &str) -> anyhow::Result<Vec<Equation>> {
+    Ok(parse_all(input, parse_input)?)
+}
+
+/// Total of the results that adding and multiplying the operands can produce.
+pub fn part1(equations: &[Equation]) -> anyhow::Result<Answer> {
+    part1::solution(equations)
+}
+
+/// Total of the results that adding, multiplying and concatenating the operands can produce.
+pub fn part2(equations: &[Equation]) -> anyhow::Result<Answer> {
+    part2::solution(equations)
+}
+
+mod part1 {
+    use anyhow::Context;
+    use aoc_common::Answer;
+    use crate::{Equation, Operation};
+
+    pub fn solution(equations: &[Equation]) -> anyhow::Result<Answer> {
+        let sum = equations.iter().filter(|e| calculate(&e.operands).contains(&e.expected_result)).map(|e| e.expected_result)
+            .try_fold(0u64, u64::checked_add)
+            .context("total calibration result overflows u64")?;
+        Ok(sum.into())
+    }
+    fn calculate(operands: &[u64]) -> Vec<u64> {
+        match operands {
+            [] => vec![],
+            [first] => vec![*first],
+            [first, rest@ .. ] => vec![calculate_rec(*first, rest, Operation::Add), calculate_rec(*first, rest, Operation::Mul)].into_iter().flatten().collect()
+        }
+    }
+
+    fn calculate_rec(prev_result: u64, operands: &[u64], operation: Operation) -> Vec<u64> {
+        match operands {
+            [] => vec![prev_result],
+            [first, rest@ .. ] => match operation.apply(prev_result, *first) {
+                None => vec![],
+                Some(result) if rest.is_empty() => vec![result],
+                Some(result) => vec![
+                    calculate_rec(result, rest, Operation::Add),
+                    calculate_rec(result, rest, Operation::Mul),
+                ].into_iter().flatten().collect(),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use proptest::prelude::*;
+
+        use super::*;
+
+        #[test]
+        fn calculate_test() {
+            // given
+            let operands = vec![10, 19];
+
+            // when
+
+            let vec = calculate(&operands);
+
+            // then
+            dbg!(vec);
+        }
+
+        proptest! {
+            #[test]
+            fn calculate_matches_enumeration_test(operands in crate::tests::operands()) {
+                // when
+                let mut results = calculate(&operands);
+                let mut expected = crate::tests::enumerate_results(&operands, &[Operation::Add, Operation::Mul]);
+
+                // then
+                results.sort();
+                expected.sort();
+                prop_assert_eq!(results, expected);
+            }
+        }
+    }
+}
+
+mod part2 {
+    use anyhow::Context;
+    use aoc_common::Answer;
+    use crate::{Equation, Operation};
+
+    pub fn solution(equations: &[Equation]) -> anyhow::Result<Answer> {
+        let sum = equations.iter().filter(|e| calculate(&e.operands).contains(&e.expected_result)).map(|e| e.expected_result)
+            .try_fold(0u64, u64::checked_add)
+            .context("total calibration result overflows u64")?;
+        Ok(sum.into())
+    }
+    fn calculate(operands: &[u64]) -> Vec<u64> {
+        match operands {
+            [] => vec![],
+            [first] => vec![*first],
+            [first, rest@ .. ] => vec![
+                calculate_rec(*first, rest, Operation::Add),
+                calculate_rec(*first, rest, Operation::Mul),
+                calculate_rec(*first, rest, Operation::Concat),
+            ].into_iter().flatten().collect()
+        }
+    }
+
+    fn calculate_rec(prev_result: u64, operands: &[u64], operation: Operation) -> Vec<u64> {
+        match operands {
+            [] => vec![prev_result],
+            [first, rest@ .. ] => match operation.apply(prev_result, *first) {
+                None => vec![],
+                Some(result) if rest.is_empty() => vec![result],
+                Some(result) => vec![
+                    calculate_rec(result, rest, Operation::Add),
+                    calculate_rec(result, rest, Operation::Mul),
+                    calculate_rec(result, rest, Operation::Concat),
+                ].into_iter().flatten().collect(),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use proptest::prelude::*;
+
+        use super::*;
+
+        proptest! {
+            #[test]
+            fn calculate_matches_enumeration_test(operands in crate::tests::operands()) {
+                // when
+                let mut results = calculate(&operands);
+                let mut expected = crate::tests::enumerate_results(&operands, &[Operation::Add, Operation::Mul, Operation::Concat]);
+
+                // then
+                results.sort();
+                expected.sort();
+                prop_assert_eq!(results, expected);
+            }
+        }
+    }
+}
+
+pub mod generate {
+    use rand::Rng;
+
+    use crate::Operation;
+
+    /// Keeps the sum of all results within a u64.
+    const MAX_RESULT: u64 = u64::MAX / 1000;
+
+    /// 850 equations, as many as in the real input, of `size` operands each. Most results come
+    /// from a random choice of operators, half of them allowing concatenation, the rest are
+    /// unlikely to be reachable at all.
+    pub fn input(size: usize, rng: &mut impl Rng) -> String {
+        let mut input = String::new();
+        for _ in 0..850 {
+            let operands: Vec<u64> = (0..size.max(1)).map(|_| rng.gen_range(1..1000)).collect();
+            let operations = [Operation::Add, Operation::Mul, Operation::Concat];
+            let operations = if rng.gen_bool(0.5) { &operations[..2] } else { &operations[..] };
+            let mut result = operands[0];
+            for &operand in &operands[1..] {
+                let operation = operations[rng.gen_range(0..operations.len())];
+                result = operation.apply(result, operand)
+                    .filter(|&result| result <= MAX_RESULT)
+                    .unwrap_or(result + operand);
+            }
+            if rng.gen_bool(0.3) {
+                result += rng.gen_range(1..10);
+            }
+            let operands: Vec<_> = operands.iter().map(u64::to_string).collect();
+            input.push_str(&format!("{result}: {}\n", operands.join(" ")));
+        }
+        input
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Operation {
+    Add, Mul, Concat
+}
+
+impl Operation {
+    /// `None` when the result does not fit a u64, dropping that combination of operators.
+    pub fn apply(self, left: u64, right: u64) -> Option<u64> {
+        match self {
+            Operation::Add => left.checked_add(right),
+            Operation::Mul => left.checked_mul(right),
+            Operation::Concat => {
+                let shift = 10u64.checked_pow(right.checked_ilog10().unwrap_or(0) + 1)?;
+                left.checked_mul(shift)?.checked_add(right)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Equation {
+    pub expected_result: u64,
+    pub operands: Vec<u64>,
+}
+
+fn parse_input(input: &str) -> IResult<'_, Vec<Equation>> {
+    lines(parse_equation)(input)
+}
+
+fn parse_equation(input: &str) -> IResult<'_, Equation> {
+    let (input, (expected_result, _, operands)) = context("equation", |i| (complete::u64, char(':'), many1(parse_operand)).parse(i))(input)?;
+    Ok((input, Equation { expected_result, operands }))
+}
+
+fn parse_operand(input: &str) -> IResult<'_, u64> {
+    let (input, (_, a)) = (char(' '), complete::u64).parse(input)?;
+    Ok((input, a))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parse::ParseError;
+    use proptest::prelude::*;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    /// Up to six operands of up to three digits, so that even concatenating all of them fits a u64.
+    pub(crate) fn operands() -> impl Strategy<Value=Vec<u64>> {
+        prop::collection::vec(1..1000u64, 1..=6)
+    }
+
+    /// Evaluates the operands left to right with every combination of `operations`, counting the
+    /// combinations in base `operations.len()`.
+    pub(crate) fn enumerate_results(operands: &[u64], operations: &[Operation]) -> Vec<u64> {
+        let combinations = operations.len().pow(operands.len() as u32 - 1);
+        (0..combinations)
+            .map(|mut combination| {
+                operands[1..].iter().fold(operands[0], |result, &operand| {
+                    let operation = operations[combination % operations.len()];
+                    combination /= operations.len();
+                    match operation {
+                        Operation::Add => result + operand,
+                        Operation::Mul => result * operand,
+                        Operation::Concat => result * 10u64.pow(operand.ilog10() + 1) + operand,
+                    }
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_test() {
+        // given
+        let input = "190: 10 19
+3267: 81 40 27";
+
+        // when
+        let (_, equations) = parse_input(input).unwrap();
+
+        // then
+        assert_eq!(equations, vec![
+            Equation {expected_result: 190, operands: vec![10, 19]},
+            Equation {expected_result: 3267, operands: vec![81, 40, 27]},
+        ]);
+    }
+
+    #[test]
+    fn apply_test() {
+        assert_eq!(Operation::Concat.apply(15, 6), Some(156));
+        assert_eq!(Operation::Concat.apply(12, 0), Some(120));
+        assert_eq!(Operation::Concat.apply(u64::MAX / 10, 9), None);
+        assert_eq!(Operation::Mul.apply(u64::MAX, 2), None);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_line_test() {
+        // given
+        let input = "190: 10 19
+3267 81 40 27
+83: 17 5";
+
+        // when
+        let err = Day7::parse(input).unwrap_err();
+
+        // then
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (2, 5, "`:`"));
+        assert_eq!(err.context, Some("equation"));
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+        #[test]
+        fn generated_equations_have_size_operands_test(size in 1..6usize, seed in any::<u64>()) {
+            // when
+            let equations = parse(&generate::input(size, &mut StdRng::seed_from_u64(seed))).unwrap();
+
+            // then
+            prop_assert_eq!(equations.len(), 850);
+            prop_assert!(equations.iter().all(|equation| equation.operands.len() == size));
+        }
+    }
+}
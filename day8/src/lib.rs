@@ -0,0 +1,254 @@
+use aoc_common::{Answer, Grid, Point, Solution};
+use rand::rngs::StdRng;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    type Input = Map;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        part2(input)
+    }
+
+    fn generate(size: usize, rng: &mut StdRng) -> anyhow::Result<String> {
+        Ok(generate::input(size, rng))
+    }
+}
+
+aoc_common::example_tests!(crate::Day8);
+
+/// Parses the map, every character other than `.` being an antenna of that frequency.
+pub fn parse(input: &str) -> anyhow::Result<Map> {
+    let grid: Grid<char> = input.parse()?;
+    let nodes = grid.iter()
+        .filter_map(|(pos, &c)| match c {
+            '.' => None,
+            _ => Some(Node { pos, freq: c })
+        })
+        .collect();
+    Ok(Map { nodes, width: grid.width(), height: grid.height() })
+}
+
+/// Number of distinct antinodes on the map.
+pub fn part1(map: &Map) -> anyhow::Result<Answer> {
+    part1::solution(map)
+}
+
+/// Number of distinct positions in line with two antennas of the same frequency.
+pub fn part2(map: &Map) -> anyhow::Result<Answer> {
+    part2::solution(map)
+}
+
+mod part1 {
+    use std::collections::HashMap;
+
+    use aoc_common::{Answer, Point};
+    use itertools::Itertools;
+    use crate::{Frequency, Map};
+
+    pub fn solution(map: &Map) -> anyhow::Result<Answer> {
+        let &Map { ref nodes, width, height } = map;
+
+        let fer_to_pos: HashMap<Frequency, Vec<Point>> = nodes.iter()
+            .fold(HashMap::new(), |mut map, node| {
+                map.entry(node.freq).or_default().push(node.pos);
+                map
+            });
+        let antinodes: Vec<_> = fer_to_pos.values()
+            .flat_map(|v| calculate_antinodes(v))
+            .collect();
+        let count_of_antinodes = antinodes.iter()
+            .unique()
+            .filter(|an| an.is_within(width, height))
+            .count();
+        Ok(count_of_antinodes.into())
+    }
+
+    fn calculate_antinodes(nodes: &[Point]) -> Vec<Point> {
+        let mut result = vec![];
+        for &l in nodes.iter() {
+            for &r in nodes.iter() {
+                if l == r {
+                    continue;
+                }
+                result.push(l - r + l);
+            }
+        }
+        result
+    }
+}
+
+mod part2 {
+    use std::collections::HashMap;
+
+    use aoc_common::{Answer, Point};
+    use itertools::Itertools;
+    use crate::{Frequency, Map};
+
+    pub fn solution(map: &Map) -> anyhow::Result<Answer> {
+        let &Map { ref nodes, width, height } = map;
+
+        let fer_to_pos: HashMap<Frequency, Vec<Point>> = nodes.iter()
+            .fold(HashMap::new(), |mut map, node| {
+                map.entry(node.freq).or_default().push(node.pos);
+                map
+            });
+        let antinodes: Vec<_> = fer_to_pos.values()
+            .flat_map(|v| calculate_antinodes(v, width, height))
+            .collect();
+        let count_of_antinodes = antinodes.iter()
+            .unique()
+            .count();
+        Ok(count_of_antinodes.into())
+    }
+
+    fn calculate_antinodes(nodes: &[Point], width: usize, height: usize) -> Vec<Point> {
+        let mut result = vec![];
+        for &l in nodes.iter() {
+            'x_loop: for &r in nodes.iter() {
+                if l == r {
+                    result.push(l);
+                    continue 'x_loop;
+                }
+                let diff = l - r;
+                let mut an = diff + l;
+                'inner: loop {
+                    if !an.is_within(width, height) {
+                        break 'inner;
+                    };
+                    result.push(an);
+                    an = diff + an;
+                }
+            }
+        }
+        result
+    }
+}
+
+pub mod generate {
+    use rand::Rng;
+
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    /// A `size` by `size` map with antennas on about one cell in sixteen, as dense as the real
+    /// input, in groups of four sharing a frequency.
+    pub fn input(size: usize, rng: &mut impl Rng) -> String {
+        let mut cells = vec![b'.'; size * size];
+        let antennas = (cells.len() / 16).max(cells.len().min(4)) / 4 * 4;
+        for antenna in 0..antennas {
+            let cell = loop {
+                let cell = rng.gen_range(0..cells.len());
+                if cells[cell] == b'.' {
+                    break cell;
+                }
+            };
+            cells[cell] = FREQUENCIES[antenna / 4 % FREQUENCIES.len()];
+        }
+
+        let mut input = String::with_capacity(size * (size + 1));
+        for row in cells.chunks(size) {
+            input.push_str(std::str::from_utf8(row).expect("cells are ascii"));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+pub type Frequency = char;
+
+#[derive(Clone, Debug, PartialOrd, PartialEq)]
+pub struct Node {
+    pub pos: Point,
+    pub freq: Frequency,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Map {
+    pub nodes: Vec<Node>,
+    pub width: usize,
+    pub height: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use proptest::prelude::*;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    type Antennas = Vec<((isize, isize), Frequency)>;
+
+    /// Maps where every frequency has at least two antennas, as in the puzzle input.
+    fn map() -> impl Strategy<Value=(usize, usize, Antennas)> {
+        (1..10usize, 1..10usize).prop_flat_map(|(width, height)| {
+            let cells = prop::sample::subsequence((0..width * height).collect::<Vec<_>>(), 0..=(width * height).min(8)).prop_shuffle();
+            let frequencies = prop::collection::vec(prop::sample::select(vec!['a', 'A', '0']), 4);
+            (Just(width), Just(height), cells, frequencies).prop_map(|(width, height, cells, frequencies)| {
+                let antennas = cells.chunks_exact(2).zip(frequencies)
+                    .flat_map(|(pair, freq)| pair.iter().map(move |&cell| (((cell % width) as isize, (cell / width) as isize), freq)))
+                    .collect();
+                (width, height, antennas)
+            })
+        })
+    }
+
+    /// Counts the cells that satisfy `is_antinode` for some pair of distinct antennas sharing a frequency.
+    fn antinode_oracle(width: usize, height: usize, antennas: &Antennas, is_antinode: impl Fn((isize, isize), (isize, isize), (isize, isize)) -> bool) -> usize {
+        let cells = (0..height as isize).flat_map(|y| (0..width as isize).map(move |x| (x, y)));
+        cells
+            .filter(|&c| antennas.iter().any(|&(a, fa)| antennas.iter().any(|&(b, fb)| a != b && fa == fb && is_antinode(c, a, b))))
+            .count()
+    }
+
+    proptest! {
+        #[test]
+        fn solution_matches_oracle_test((width, height, antennas) in map()) {
+            // given
+            let rows: Vec<String> = (0..height as isize)
+                .map(|y| (0..width as isize)
+                    .map(|x| antennas.iter().find(|(pos, _)| *pos == (x, y)).map_or('.', |&(_, freq)| freq))
+                    .collect())
+                .collect();
+            // `b` lies halfway between `a` and the antinode
+            let part1 = antinode_oracle(width, height, &antennas, |c, a, b| (c.0 - b.0, c.1 - b.1) == (b.0 - a.0, b.1 - a.1));
+            // the antinode is a whole number of steps from `a` to `b` away from `a`
+            let part2 = antinode_oracle(width, height, &antennas, |c, a, b| {
+                let (dx, dy, cx, cy) = (b.0 - a.0, b.1 - a.1, c.0 - a.0, c.1 - a.1);
+                cx * dy == cy * dx && if dx != 0 { cx % dx == 0 } else { cy % dy == 0 }
+            });
+
+            // when
+            let map = Day8::parse(&rows.join("\n")).unwrap();
+
+            // then
+            prop_assert_eq!(Day8::part1(&map).unwrap(), Answer::from(part1));
+            prop_assert_eq!(Day8::part2(&map).unwrap(), Answer::from(part2));
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+        #[test]
+        fn generated_antennas_come_in_fours_test(size in 1..30usize, seed in any::<u64>()) {
+            // when
+            let map = parse(&generate::input(size, &mut StdRng::seed_from_u64(seed))).unwrap();
+
+            // then
+            let cells = size * size;
+            prop_assert_eq!((map.width, map.height), (size, size));
+            prop_assert_eq!(map.nodes.len(), (cells / 16).max(cells.min(4)) / 4 * 4);
+            prop_assert!(map.nodes.iter().counts_by(|node| node.freq).values().all(|count| count % 4 == 0));
+        }
+    }
+}
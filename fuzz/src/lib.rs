@@ -0,0 +1,16 @@
+//! Fuzz targets feeding arbitrary bytes to every day, run with `cargo +nightly fuzz run day7`
+//! from this directory.
+//!
+//! Rejecting the input or failing to solve it is fine, only a panic or a hang is a bug. Day 7's
+//! search grows exponentially with the operands of an equation, so give it a longer `-timeout`.
+
+use aoc_common::Solution;
+
+/// Parses `data` and solves both parts of it, ignoring every error.
+pub fn solve<S: Solution>(data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else { return };
+    if let Ok(parsed) = S::parse(input) {
+        let _ = S::part1(&parsed);
+        let _ = S::part2(&parsed);
+    }
+}